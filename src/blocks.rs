@@ -0,0 +1,208 @@
+use slack_morphism::prelude::*;
+use std::collections::HashSet;
+
+/// Slack's Block Kit limits for messages.
+const MAX_BLOCKS: usize = 50;
+const MAX_BLOCK_ID: usize = 255;
+const MAX_SECTION_TEXT: usize = 3000;
+const MAX_SECTION_FIELDS: usize = 10;
+const MAX_FIELD_TEXT: usize = 2000;
+const MAX_HEADER_TEXT: usize = 150;
+const MAX_CONTEXT_ELEMENTS: usize = 10;
+const MAX_ACTIONS_ELEMENTS: usize = 25;
+
+/// Read Block Kit JSON from a file (`-` for stdin), validate it and parse it into blocks.
+///
+/// Accepts either a bare array of blocks or an object with a `blocks` array
+/// (what Block Kit Builder exports).
+pub fn load_blocks(path: &str) -> Result<Vec<SlackBlock>, String> {
+    let content = if path == "-" {
+        std::io::read_to_string(std::io::stdin())
+    } else {
+        std::fs::read_to_string(path)
+    }
+    .map_err(|e| format!("Failed to read blocks from {}: {}", path, e))?;
+
+    parse_blocks(&content)
+}
+
+/// Validate Block Kit JSON and parse it into blocks.
+pub fn parse_blocks(json: &str) -> Result<Vec<SlackBlock>, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| format!("Invalid blocks JSON: {}", e))?;
+    let blocks = match value {
+        serde_json::Value::Object(mut obj) => obj
+            .remove("blocks")
+            .ok_or("Blocks JSON object has no 'blocks' array")?,
+        other => other,
+    };
+
+    validate(&blocks)?;
+
+    serde_json::from_value(blocks).map_err(|e| format!("Invalid blocks: {}", e))
+}
+
+/// Check structure and size limits that Slack would otherwise reject with a bare `invalid_blocks`.
+fn validate(blocks: &serde_json::Value) -> Result<(), String> {
+    let blocks = blocks.as_array().ok_or("Blocks must be a JSON array")?;
+    if blocks.is_empty() {
+        return Err("Blocks array is empty".to_string());
+    }
+    if blocks.len() > MAX_BLOCKS {
+        return Err(format!(
+            "Too many blocks: {} (max {})",
+            blocks.len(),
+            MAX_BLOCKS
+        ));
+    }
+
+    let mut block_ids = HashSet::new();
+    for (i, block) in blocks.iter().enumerate() {
+        let err = |msg: String| format!("Block {}: {}", i, msg);
+        let kind = block["type"]
+            .as_str()
+            .ok_or_else(|| err("missing 'type'".to_string()))?;
+
+        if let Some(id) = block["block_id"].as_str() {
+            if id.chars().count() > MAX_BLOCK_ID {
+                return Err(err(format!("block_id longer than {}", MAX_BLOCK_ID)));
+            }
+            if !block_ids.insert(id) {
+                return Err(err(format!("duplicate block_id '{}'", id)));
+            }
+        }
+
+        match kind {
+            "section" => {
+                let fields = block["fields"].as_array();
+                if block["text"].is_null() && fields.is_none() {
+                    return Err(err("section needs 'text' or 'fields'".to_string()));
+                }
+                check_text(&block["text"], MAX_SECTION_TEXT).map_err(err)?;
+                if let Some(fields) = fields {
+                    if fields.len() > MAX_SECTION_FIELDS {
+                        return Err(err(format!(
+                            "section has more than {} fields",
+                            MAX_SECTION_FIELDS
+                        )));
+                    }
+                    for field in fields {
+                        check_text(field, MAX_FIELD_TEXT).map_err(err)?;
+                    }
+                }
+            }
+            "header" => {
+                if block["text"]["type"] != "plain_text" {
+                    return Err(err("header text must be plain_text".to_string()));
+                }
+                check_text(&block["text"], MAX_HEADER_TEXT).map_err(err)?;
+            }
+            "context" => check_elements(block, MAX_CONTEXT_ELEMENTS).map_err(err)?,
+            "actions" => check_elements(block, MAX_ACTIONS_ELEMENTS).map_err(err)?,
+            "image" => {
+                if block["alt_text"].as_str().is_none() {
+                    return Err(err("image needs 'alt_text'".to_string()));
+                }
+                if block["image_url"].is_null() && block["slack_file"].is_null() {
+                    return Err(err("image needs 'image_url' or 'slack_file'".to_string()));
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Check a text object (if present) is well-formed and within `max` characters.
+fn check_text(text: &serde_json::Value, max: usize) -> Result<(), String> {
+    if text.is_null() {
+        return Ok(());
+    }
+    let content = text["text"]
+        .as_str()
+        .ok_or("text object needs a 'text' string")?;
+    if !matches!(text["type"].as_str(), Some("plain_text" | "mrkdwn")) {
+        return Err("text type must be 'plain_text' or 'mrkdwn'".to_string());
+    }
+    if content.chars().count() > max {
+        return Err(format!("text longer than {} characters", max));
+    }
+    Ok(())
+}
+
+fn check_elements(block: &serde_json::Value, max: usize) -> Result<(), String> {
+    let elements = block["elements"]
+        .as_array()
+        .ok_or("missing 'elements' array")?;
+    if elements.is_empty() || elements.len() > max {
+        return Err(format!("needs 1 to {} elements", max));
+    }
+    Ok(())
+}
+
+/// Plain-text fallback for notifications: the first header or section text.
+pub fn fallback_text(blocks: &[SlackBlock]) -> Option<String> {
+    blocks.iter().find_map(|block| {
+        let value = serde_json::to_value(block).ok()?;
+        match value["type"].as_str()? {
+            "header" | "section" => value["text"]["text"].as_str().map(String::from),
+            _ => None,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_array() {
+        let blocks = parse_blocks(
+            r#"[{"type": "section", "text": {"type": "mrkdwn", "text": "*Deployed*"}},
+                {"type": "divider"}]"#,
+        )
+        .unwrap();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(fallback_text(&blocks).as_deref(), Some("*Deployed*"));
+    }
+
+    #[test]
+    fn test_parse_builder_object() {
+        let blocks = parse_blocks(
+            r#"{"blocks": [{"type": "header", "text": {"type": "plain_text", "text": "Hi"}}]}"#,
+        )
+        .unwrap();
+        assert_eq!(blocks.len(), 1);
+    }
+
+    #[test]
+    fn test_missing_type() {
+        let err = parse_blocks(r#"[{"text": {"type": "mrkdwn", "text": "x"}}]"#).unwrap_err();
+        assert!(err.contains("Block 0"));
+    }
+
+    #[test]
+    fn test_section_text_too_long() {
+        let json = format!(
+            r#"[{{"type": "section", "text": {{"type": "mrkdwn", "text": "{}"}}}}]"#,
+            "a".repeat(MAX_SECTION_TEXT + 1)
+        );
+        assert!(parse_blocks(&json).is_err());
+    }
+
+    #[test]
+    fn test_duplicate_block_id() {
+        let json =
+            r#"[{"type": "divider", "block_id": "a"}, {"type": "divider", "block_id": "a"}]"#;
+        assert!(parse_blocks(json).unwrap_err().contains("duplicate"));
+    }
+
+    #[test]
+    fn test_too_many_blocks() {
+        let json = format!(
+            "[{}]",
+            vec![r#"{"type": "divider"}"#; MAX_BLOCKS + 1].join(",")
+        );
+        assert!(parse_blocks(&json).is_err());
+    }
+}
@@ -1,12 +1,39 @@
 use crate::config::Config;
-use crate::error::Result;
-use futures::TryStreamExt;
+use crate::error::{Result, SlackCliError};
+use futures::{StreamExt, TryStreamExt};
 use slack_morphism::prelude::*;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 pub type HyperConnector = SlackClientHyperHttpsConnector;
 
+/// Max in-flight per-user calls (`users.info`, `users.getPresence`) in bulk lookups.
+pub const USER_LOOKUP_CONCURRENCY: usize = 8;
+
+/// Times a rate-limited call is retried before its error is returned.
+const RATE_LIMIT_RETRIES: usize = 3;
+
+/// Run `call`, waiting out rate limits a few times before giving up.
+pub async fn retry_rate_limited<T, F, Fut>(mut call: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match call().await {
+            Err(e) if attempt < RATE_LIMIT_RETRIES => {
+                let Some(wait) = e.rate_limit_wait() else {
+                    return Err(e);
+                };
+                attempt += 1;
+                tokio::time::sleep(wait).await;
+            }
+            result => return result,
+        }
+    }
+}
+
 pub struct Client {
     inner: Arc<SlackHyperClient>,
     token: SlackApiToken,
@@ -46,6 +73,15 @@ impl Client {
     /// Resolve a channel name or ID to a SlackChannelId.
     /// Accepts: `C1RCG46LS`, `#general`, `general`
     pub async fn resolve_channel(&self, channel: &str) -> Result<SlackChannelId> {
+        self.find_channel(channel, true).await
+    }
+
+    /// Like `resolve_channel`, but names also match archived channels.
+    pub async fn resolve_archived_channel(&self, channel: &str) -> Result<SlackChannelId> {
+        self.find_channel(channel, false).await
+    }
+
+    async fn find_channel(&self, channel: &str, exclude_archived: bool) -> Result<SlackChannelId> {
         if Self::looks_like_id(channel) {
             return Ok(SlackChannelId::new(channel.to_string()));
         }
@@ -56,7 +92,7 @@ impl Client {
         loop {
             let mut req = SlackApiConversationsListRequest::new()
                 .with_limit(200)
-                .with_exclude_archived(true)
+                .with_exclude_archived(exclude_archived)
                 .with_types(vec![
                     SlackConversationType::Public,
                     SlackConversationType::Private,
@@ -145,6 +181,88 @@ impl Client {
         Ok(result.into_iter().map(|o| o.unwrap()).collect())
     }
 
+    /// Call a Web API method with query parameters (for APIs not in slack-morphism).
+    pub async fn api_get(
+        &self,
+        method: &str,
+        params: &[(&str, &str)],
+    ) -> Result<serde_json::Value> {
+        let query: Vec<String> = params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, urlencoding::encode(v)))
+            .collect();
+        let url = format!("https://slack.com/api/{}?{}", method, query.join("&"));
+
+        let response = reqwest::Client::new()
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.token_str))
+            .send()
+            .await
+            .map_err(|e| SlackCliError::Api(e.to_string()))?;
+        Self::check_api_response(response).await
+    }
+
+    /// Call a Web API method with a JSON body (for APIs not in slack-morphism).
+    pub async fn api_post(
+        &self,
+        method: &str,
+        body: &serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let url = format!("https://slack.com/api/{}", method);
+
+        let response = reqwest::Client::new()
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", self.token_str))
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| SlackCliError::Api(e.to_string()))?;
+        Self::check_api_response(response).await
+    }
+
+    async fn check_api_response(response: reqwest::Response) -> Result<serde_json::Value> {
+        let value: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| SlackCliError::Api(e.to_string()))?;
+
+        if value["ok"].as_bool() != Some(true) {
+            let error = value["error"]
+                .as_str()
+                .unwrap_or("Unknown error")
+                .to_string();
+            return Err(if error == "ratelimited" {
+                SlackCliError::RateLimit(error)
+            } else {
+                SlackCliError::Api(error)
+            });
+        }
+        Ok(value)
+    }
+
+    /// `users.info` for many users, a few at a time, retrying rate limits.
+    pub async fn lookup_users(&self, ids: &[String]) -> HashMap<String, Result<SlackUser>> {
+        futures::stream::iter(ids.iter().map(|id| async move {
+            let session = self.session();
+            let request = SlackApiUsersInfoRequest::new(SlackUserId::new(id.clone()));
+            let user =
+                retry_rate_limited(|| async { Ok(session.users_info(&request).await?.user) }).await;
+            (id.clone(), user)
+        }))
+        .buffer_unordered(USER_LOOKUP_CONCURRENCY)
+        .collect()
+        .await
+    }
+
+    /// Usernames by ID. Users that can't be looked up are left out.
+    pub async fn user_names(&self, ids: &[String]) -> HashMap<String, String> {
+        self.lookup_users(ids)
+            .await
+            .into_iter()
+            .filter_map(|(id, user)| Some((id, user.ok()?.name?)))
+            .collect()
+    }
+
     /// Get the inner SlackHyperClient for socket mode reuse.
     pub fn inner(&self) -> &Arc<SlackHyperClient> {
         &self.inner
@@ -155,7 +273,7 @@ impl Client {
             && !s.contains(|c: char| c.is_lowercase())
     }
 
-    fn looks_like_user_id(s: &str) -> bool {
+    pub fn looks_like_user_id(s: &str) -> bool {
         (s.starts_with('U') || s.starts_with('W')) && !s.contains(|c: char| c.is_lowercase())
     }
 
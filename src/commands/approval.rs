@@ -0,0 +1,358 @@
+use crate::client::Client;
+use crate::commands::messages::fetch_reactions;
+use crate::commands::watch::ChannelWakeups;
+use crate::error::{Result, SlackCliError};
+use crate::output::{HumanReadable, Output};
+use colored::Colorize;
+use regex::Regex;
+use serde::Serialize;
+use slack_morphism::prelude::*;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// The reaction or reply that settled an approval gate.
+#[derive(Debug, Serialize)]
+pub struct Approval {
+    pub channel: String,
+    pub ts: String,
+    pub approved: bool,
+    pub user: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    /// Set when a reaction decided it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reaction: Option<String>,
+    /// Set when a thread reply decided it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    pub waited_secs: u64,
+}
+
+impl HumanReadable for Approval {
+    fn print_human(&self) {
+        let verdict = if self.approved {
+            "Approved".green().bold()
+        } else {
+            "Rejected".red().bold()
+        };
+        let who = self.user_name.as_deref().unwrap_or(&self.user);
+        let how = match (&self.reaction, &self.text) {
+            (Some(reaction), _) => format!(":{}:", reaction),
+            (None, Some(text)) => format!("\"{}\"", text),
+            (None, None) => String::new(),
+        };
+        println!("{} by {} {}", verdict, who.cyan(), how);
+        println!("  waited {}s", self.waited_secs);
+    }
+}
+
+/// Options for `messages await`.
+#[derive(Debug)]
+pub struct AwaitOptions {
+    /// Reaction that approves
+    pub reaction: Option<String>,
+    /// Reaction that rejects
+    pub reject: Option<String>,
+    /// Only these users (IDs or @names) count; otherwise anyone but yourself
+    pub from: Vec<String>,
+    /// A thread reply matching this regex approves
+    pub reply_matches: Option<String>,
+    pub timeout: Option<Duration>,
+    /// Time between polls
+    pub interval: Duration,
+}
+
+struct Gate {
+    approve: Option<String>,
+    reject: Option<String>,
+    from: HashSet<String>,
+    me: String,
+    pattern: Option<Regex>,
+    /// Newest reply already checked, so each poll only reads new ones
+    replies_after: String,
+}
+
+struct Decision {
+    approved: bool,
+    user: String,
+    reaction: Option<String>,
+    reply_ts: Option<String>,
+    text: Option<String>,
+}
+
+impl Gate {
+    fn counts(&self, user: &str) -> bool {
+        if self.from.is_empty() {
+            user != self.me
+        } else {
+            self.from.contains(user)
+        }
+    }
+
+    /// First allowed user who reacted with `wanted` (any skin tone).
+    fn reacted(&self, reactions: &[(String, Vec<String>)], wanted: &str) -> Option<String> {
+        reactions
+            .iter()
+            .filter(|(name, _)| base_reaction(name) == wanted)
+            .flat_map(|(_, users)| users)
+            .find(|user| self.counts(user))
+            .cloned()
+    }
+}
+
+/// `:+1::skin-tone-2:` → `+1`
+fn base_reaction(name: &str) -> &str {
+    let name = name.trim_matches(':');
+    name.split("::").next().unwrap_or(name)
+}
+
+/// Block until a message is approved by reaction or thread reply. A rejection reaction or
+/// the timeout ends the wait with an error, so scripts can gate on the exit code.
+pub async fn await_approval(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    ts: &str,
+    app_token: Option<&str>,
+    opts: &AwaitOptions,
+) -> Result<()> {
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let mut from = HashSet::new();
+    for user in &opts.from {
+        from.insert(client.resolve_user(user).await?.0);
+    }
+    let pattern = opts
+        .reply_matches
+        .as_deref()
+        .map(Regex::new)
+        .transpose()
+        .map_err(|e| SlackCliError::Config(format!("Invalid --reply-matches pattern: {}", e)))?;
+
+    let mut gate = Gate {
+        approve: opts
+            .reaction
+            .as_deref()
+            .map(|r| base_reaction(r).to_string()),
+        reject: opts.reject.as_deref().map(|r| base_reaction(r).to_string()),
+        from,
+        me: client.auth_test().await?.user_id.0,
+        pattern,
+        replies_after: ts.to_string(),
+    };
+
+    let wakeups = match app_token {
+        Some(token) => Some(ChannelWakeups::connect(client, token, &channel_id).await?),
+        None => None,
+    };
+
+    output.status(&format!("waiting for approval of {} in {}...", ts, channel));
+
+    let started = Instant::now();
+    let deadline = opts.timeout.map(|t| tokio::time::Instant::now() + t);
+    let decision = loop {
+        match check(client, &channel_id, ts, &mut gate).await {
+            Ok(Some(decision)) => break Some(decision),
+            Ok(None) => {}
+            // A rate limit or network blip shouldn't fail the gate as if it were rejected
+            Err(e) if e.is_transient() => output.error(&format!("check failed: {}", e)),
+            Err(e) => return Err(e),
+        }
+
+        let timeout = async {
+            match deadline {
+                Some(deadline) => tokio::time::sleep_until(deadline).await,
+                None => std::future::pending().await,
+            }
+        };
+        let wake = async {
+            match &wakeups {
+                Some(wakeups) => wakeups.wait().await,
+                None => std::future::pending().await,
+            }
+        };
+        tokio::select! {
+            _ = tokio::time::sleep(opts.interval) => {}
+            _ = wake => {}
+            _ = timeout => break None,
+        }
+    };
+
+    if let Some(wakeups) = &wakeups {
+        wakeups.shutdown().await;
+    }
+
+    let Some(decision) = decision else {
+        return Err(SlackCliError::Timeout(format!(
+            "no approval for {} after {}s",
+            ts,
+            started.elapsed().as_secs()
+        )));
+    };
+
+    let user_name = client
+        .user_names(std::slice::from_ref(&decision.user))
+        .await
+        .remove(&decision.user);
+
+    let approval = Approval {
+        channel: channel_id.0,
+        ts: ts.to_string(),
+        approved: decision.approved,
+        user: decision.user,
+        user_name,
+        reaction: decision.reaction,
+        reply_ts: decision.reply_ts,
+        text: decision.text,
+        waited_secs: started.elapsed().as_secs(),
+    };
+    output.print(&approval);
+
+    if !approval.approved {
+        return Err(SlackCliError::Rejected(format!(
+            "{} by {}",
+            ts,
+            approval.user_name.as_deref().unwrap_or(&approval.user)
+        )));
+    }
+
+    Ok(())
+}
+
+/// One poll: rejection reactions win over approvals, then new thread replies are matched.
+async fn check(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    ts: &str,
+    gate: &mut Gate,
+) -> Result<Option<Decision>> {
+    if gate.approve.is_some() || gate.reject.is_some() {
+        let reactions: Vec<(String, Vec<String>)> = fetch_reactions(client, channel_id, ts)
+            .await?
+            .into_iter()
+            .map(|r| (r.name, r.users))
+            .collect();
+
+        let by_reaction = |wanted: &Option<String>, approved: bool| {
+            let wanted = wanted.as_deref()?;
+            gate.reacted(&reactions, wanted).map(|user| Decision {
+                approved,
+                user,
+                reaction: Some(wanted.to_string()),
+                reply_ts: None,
+                text: None,
+            })
+        };
+        if let Some(decision) =
+            by_reaction(&gate.reject, false).or_else(|| by_reaction(&gate.approve, true))
+        {
+            return Ok(Some(decision));
+        }
+    }
+
+    let Some(pattern) = &gate.pattern else {
+        return Ok(None);
+    };
+
+    let session = client.session();
+    let request =
+        SlackApiConversationsRepliesRequest::new(channel_id.clone(), SlackTs::new(ts.to_string()))
+            .with_oldest(SlackTs::new(gate.replies_after.clone()))
+            .with_inclusive(false)
+            .with_limit(200);
+    let response = session.conversations_replies(&request).await?;
+
+    let mut decision = None;
+    for message in response.messages {
+        let reply_ts = message.origin.ts.0;
+        // The parent always comes back first, whatever `oldest` says
+        if reply_ts == ts {
+            continue;
+        }
+        gate.replies_after = reply_ts.clone();
+
+        let Some(user) = message.sender.user.map(|u| u.0) else {
+            continue;
+        };
+        let text = message.content.text.unwrap_or_default();
+        if decision.is_none() && gate.counts(&user) && pattern.is_match(&text) {
+            decision = Some(Decision {
+                approved: true,
+                user,
+                reaction: None,
+                reply_ts: Some(reply_ts),
+                text: Some(text),
+            });
+        }
+    }
+
+    Ok(decision)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gate(from: &[&str]) -> Gate {
+        Gate {
+            approve: Some("white_check_mark".to_string()),
+            reject: Some("x".to_string()),
+            from: from.iter().map(|u| u.to_string()).collect(),
+            me: "UME".to_string(),
+            pattern: None,
+            replies_after: "1.0".to_string(),
+        }
+    }
+
+    fn reactions(list: &[(&str, &[&str])]) -> Vec<(String, Vec<String>)> {
+        list.iter()
+            .map(|(name, users)| {
+                (
+                    name.to_string(),
+                    users.iter().map(|u| u.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_base_reaction() {
+        assert_eq!(base_reaction("+1"), "+1");
+        assert_eq!(base_reaction(":+1:"), "+1");
+        assert_eq!(base_reaction("+1::skin-tone-2"), "+1");
+        assert_eq!(base_reaction(":+1::skin-tone-6:"), "+1");
+    }
+
+    #[test]
+    fn test_counts_anyone_but_me() {
+        let gate = gate(&[]);
+        assert!(gate.counts("U1"));
+        assert!(!gate.counts("UME"));
+    }
+
+    #[test]
+    fn test_counts_only_listed_approvers() {
+        let gate = gate(&["U1", "UME"]);
+        assert!(gate.counts("U1"));
+        assert!(!gate.counts("U2"));
+        // Listing yourself explicitly lets your own reaction count
+        assert!(gate.counts("UME"));
+    }
+
+    #[test]
+    fn test_reacted() {
+        let gate = gate(&["U2"]);
+        let list = reactions(&[
+            ("white_check_mark", &["U1"]),
+            ("white_check_mark::skin-tone-3", &["U2"]),
+            ("x", &["U1"]),
+        ]);
+        assert_eq!(
+            gate.reacted(&list, "white_check_mark").as_deref(),
+            Some("U2")
+        );
+        assert_eq!(gate.reacted(&list, "x"), None);
+    }
+}
@@ -0,0 +1,488 @@
+use crate::client::Client;
+use crate::error::{Result, SlackCliError};
+use crate::output::{HumanReadable, Output};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use slack_morphism::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub id: String,
+    #[serde(alias = "channel_id")]
+    pub channel: String,
+    pub title: String,
+    pub link: Option<String>,
+    pub emoji: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+impl HumanReadable for Bookmark {
+    fn print_human(&self) {
+        let emoji = self
+            .emoji
+            .as_deref()
+            .map(|e| format!("{} ", e))
+            .unwrap_or_default();
+        println!("{}{} {}", emoji, self.title.bold(), self.id.dimmed());
+        if let Some(link) = &self.link {
+            println!("  {}", link.cyan());
+        }
+    }
+}
+
+/// One entry of a `--from-file` bookmark set. `bookmarks list --json` output is accepted too;
+/// entries without a link (folders) are only matched, never created.
+#[derive(Debug, Deserialize)]
+pub struct BookmarkSpec {
+    pub title: String,
+    pub link: Option<String>,
+    pub emoji: Option<String>,
+    #[serde(rename = "type", default = "default_kind")]
+    pub kind: String,
+}
+
+fn default_kind() -> String {
+    "link".to_string()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BookmarkAction {
+    Added,
+    Updated,
+    /// Re-created because its type changed
+    Replaced,
+    Removed,
+    /// Declared without a link, so it couldn't be created or re-created
+    Skipped,
+    Unchanged,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BookmarkChange {
+    pub action: BookmarkAction,
+    pub id: Option<String>,
+    pub title: String,
+    pub link: Option<String>,
+}
+
+impl HumanReadable for BookmarkChange {
+    fn print_human(&self) {
+        let action = match self.action {
+            BookmarkAction::Added => "+".green(),
+            BookmarkAction::Updated | BookmarkAction::Replaced => "~".yellow(),
+            BookmarkAction::Removed => "-".red(),
+            BookmarkAction::Skipped | BookmarkAction::Unchanged => "=".dimmed(),
+        };
+        println!(
+            "{} {} {}",
+            action,
+            self.title.bold(),
+            self.link.as_deref().unwrap_or_default().dimmed()
+        );
+    }
+}
+
+/// Slack stores bookmark emoji as `:name:`; accept `name` as well.
+fn normalize_emoji(emoji: &str) -> String {
+    format!(":{}:", emoji.trim_matches(':'))
+}
+
+async fn fetch_bookmarks(client: &Client, channel_id: &SlackChannelId) -> Result<Vec<Bookmark>> {
+    let response = client
+        .api_get("bookmarks.list", &[("channel_id", &channel_id.0)])
+        .await?;
+    serde_json::from_value(response["bookmarks"].clone())
+        .map_err(|e| SlackCliError::Api(format!("Unexpected bookmarks.list response: {}", e)))
+}
+
+/// Find a bookmark by ID or exact title.
+fn find_bookmark<'a>(bookmarks: &'a [Bookmark], key: &str) -> Result<&'a Bookmark> {
+    bookmarks
+        .iter()
+        .find(|b| b.id == key)
+        .or_else(|| bookmarks.iter().find(|b| b.title == key))
+        .ok_or_else(|| SlackCliError::Api(format!("Bookmark not found: {}", key)))
+}
+
+fn parse_bookmark(response: &serde_json::Value) -> Result<Bookmark> {
+    serde_json::from_value(response["bookmark"].clone())
+        .map_err(|e| SlackCliError::Api(format!("Unexpected bookmarks response: {}", e)))
+}
+
+/// List bookmarks in one or more channels
+pub async fn list(client: &Client, output: &Output, channels: &[String]) -> Result<()> {
+    let channel_ids = client.resolve_channels(channels).await?;
+
+    let mut bookmarks = Vec::new();
+    for channel_id in &channel_ids {
+        bookmarks.extend(fetch_bookmarks(client, channel_id).await?);
+    }
+
+    output.print_list(&bookmarks, &format!("Bookmarks in {}", channels.join(", ")));
+
+    Ok(())
+}
+
+/// Add a bookmark to a channel
+pub async fn add(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    title: &str,
+    link: &str,
+    emoji: Option<&str>,
+    kind: &str,
+) -> Result<()> {
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let bookmark = add_bookmark(client, &channel_id, title, link, emoji, kind).await?;
+
+    output.print(&bookmark);
+    output.success(&format!("Added bookmark '{}' to #{}", title, channel));
+
+    Ok(())
+}
+
+async fn add_bookmark(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    title: &str,
+    link: &str,
+    emoji: Option<&str>,
+    kind: &str,
+) -> Result<Bookmark> {
+    let mut body = serde_json::json!({
+        "channel_id": channel_id.0,
+        "title": title,
+        "type": kind,
+        "link": link,
+    });
+    if let Some(emoji) = emoji {
+        body["emoji"] = normalize_emoji(emoji).into();
+    }
+    let response = client.api_post("bookmarks.add", &body).await?;
+    parse_bookmark(&response)
+}
+
+/// Edit a bookmark's title, link or emoji
+pub async fn edit(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    bookmark: &str,
+    title: Option<&str>,
+    link: Option<&str>,
+    emoji: Option<&str>,
+) -> Result<()> {
+    let channel_id = client.resolve_channel(channel).await?;
+    let bookmarks = fetch_bookmarks(client, &channel_id).await?;
+    let id = find_bookmark(&bookmarks, bookmark)?.id.clone();
+
+    let updated = edit_bookmark(client, &channel_id, &id, title, link, emoji).await?;
+
+    output.print(&updated);
+    output.success(&format!(
+        "Updated bookmark '{}' in #{}",
+        updated.title, channel
+    ));
+
+    Ok(())
+}
+
+async fn edit_bookmark(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    id: &str,
+    title: Option<&str>,
+    link: Option<&str>,
+    emoji: Option<&str>,
+) -> Result<Bookmark> {
+    let mut body = serde_json::json!({
+        "channel_id": channel_id.0,
+        "bookmark_id": id,
+    });
+    if let Some(title) = title {
+        body["title"] = title.into();
+    }
+    if let Some(link) = link {
+        body["link"] = link.into();
+    }
+    if let Some(emoji) = emoji {
+        body["emoji"] = normalize_emoji(emoji).into();
+    }
+    let response = client.api_post("bookmarks.edit", &body).await?;
+    parse_bookmark(&response)
+}
+
+/// Remove a bookmark from a channel
+pub async fn remove(client: &Client, output: &Output, channel: &str, bookmark: &str) -> Result<()> {
+    let channel_id = client.resolve_channel(channel).await?;
+    let bookmarks = fetch_bookmarks(client, &channel_id).await?;
+    let found = find_bookmark(&bookmarks, bookmark)?;
+
+    remove_bookmark(client, &channel_id, &found.id).await?;
+
+    output.success(&format!(
+        "Removed bookmark '{}' from #{}",
+        found.title, channel
+    ));
+
+    Ok(())
+}
+
+async fn remove_bookmark(client: &Client, channel_id: &SlackChannelId, id: &str) -> Result<()> {
+    client
+        .api_post(
+            "bookmarks.remove",
+            &serde_json::json!({ "channel_id": channel_id.0, "bookmark_id": id }),
+        )
+        .await?;
+    Ok(())
+}
+
+/// What `apply` does for one declared bookmark.
+#[derive(Debug, PartialEq)]
+enum Step<'a> {
+    Add {
+        link: &'a str,
+    },
+    /// bookmarks.edit can't change the type, so re-create it
+    Replace {
+        old: &'a Bookmark,
+        link: &'a str,
+    },
+    Update {
+        old: &'a Bookmark,
+    },
+    Skip {
+        old: Option<&'a Bookmark>,
+    },
+    Keep {
+        old: &'a Bookmark,
+    },
+}
+
+fn plan<'a>(spec: &'a BookmarkSpec, existing: &'a [Bookmark]) -> Step<'a> {
+    let link = spec.link.as_deref();
+    let emoji = spec.emoji.as_deref().map(normalize_emoji);
+    match existing.iter().find(|b| b.title == spec.title) {
+        // Without a link there's nothing to create it from
+        None => match link {
+            Some(link) => Step::Add { link },
+            None => Step::Skip { old: None },
+        },
+        Some(old) if old.kind != spec.kind => match link.or(old.link.as_deref()) {
+            Some(link) => Step::Replace { old, link },
+            None => Step::Skip { old: Some(old) },
+        },
+        Some(old)
+            if (link.is_some() && old.link.as_deref() != link)
+                || (emoji.is_some() && old.emoji != emoji) =>
+        {
+            Step::Update { old }
+        }
+        Some(old) => Step::Keep { old },
+    }
+}
+
+/// Existing bookmarks whose title isn't declared, for `--prune`.
+fn undeclared<'a>(specs: &[BookmarkSpec], existing: &'a [Bookmark]) -> Vec<&'a Bookmark> {
+    existing
+        .iter()
+        .filter(|b| !specs.iter().any(|s| s.title == b.title))
+        .collect()
+}
+
+/// Make a channel's bookmarks match a declared set (JSON array of `{title, link?, emoji?, type?}`).
+/// Bookmarks are matched by title, and an omitted link or emoji leaves the current one alone.
+/// With `prune`, undeclared bookmarks are removed.
+pub async fn apply(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    file: &str,
+    prune: bool,
+) -> Result<()> {
+    let content = if file == "-" {
+        std::io::read_to_string(std::io::stdin())
+    } else {
+        std::fs::read_to_string(file)
+    }
+    .map_err(|e| SlackCliError::Config(format!("Failed to read bookmarks from {}: {}", file, e)))?;
+    let specs: Vec<BookmarkSpec> = serde_json::from_str(&content)
+        .map_err(|e| SlackCliError::Config(format!("Invalid bookmarks file {}: {}", file, e)))?;
+
+    let channel_id = client.resolve_channel(channel).await?;
+    let existing = fetch_bookmarks(client, &channel_id).await?;
+
+    let mut changes = Vec::new();
+    for spec in &specs {
+        let emoji = spec.emoji.as_deref();
+        let (action, id) = match plan(spec, &existing) {
+            Step::Add { link } => {
+                let added =
+                    add_bookmark(client, &channel_id, &spec.title, link, emoji, &spec.kind).await?;
+                (BookmarkAction::Added, Some(added.id))
+            }
+            Step::Replace { old, link } => {
+                // Add first, so a failed add leaves the old bookmark in place
+                let added = add_bookmark(
+                    client,
+                    &channel_id,
+                    &spec.title,
+                    link,
+                    emoji.or(old.emoji.as_deref()),
+                    &spec.kind,
+                )
+                .await?;
+                remove_bookmark(client, &channel_id, &old.id).await?;
+                (BookmarkAction::Replaced, Some(added.id))
+            }
+            Step::Update { old } => {
+                edit_bookmark(
+                    client,
+                    &channel_id,
+                    &old.id,
+                    None,
+                    spec.link.as_deref(),
+                    emoji,
+                )
+                .await?;
+                (BookmarkAction::Updated, Some(old.id.clone()))
+            }
+            Step::Skip { old } => (BookmarkAction::Skipped, old.map(|b| b.id.clone())),
+            Step::Keep { old } => (BookmarkAction::Unchanged, Some(old.id.clone())),
+        };
+        changes.push(BookmarkChange {
+            action,
+            id,
+            title: spec.title.clone(),
+            link: spec.link.clone(),
+        });
+    }
+
+    if prune {
+        for b in undeclared(&specs, &existing) {
+            remove_bookmark(client, &channel_id, &b.id).await?;
+            changes.push(BookmarkChange {
+                action: BookmarkAction::Removed,
+                id: Some(b.id.clone()),
+                title: b.title.clone(),
+                link: b.link.clone(),
+            });
+        }
+    }
+
+    let changed = changes
+        .iter()
+        .filter(|c| {
+            !matches!(
+                c.action,
+                BookmarkAction::Unchanged | BookmarkAction::Skipped
+            )
+        })
+        .count();
+    output.print_list(&changes, &format!("Bookmarks in #{}", channel));
+    output.success(&format!(
+        "Applied {} bookmark changes to #{}",
+        changed, channel
+    ));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn existing() -> Vec<Bookmark> {
+        serde_json::from_value(json!([
+            {"id": "Bk1", "channel_id": "C1", "title": "Runbook", "link": "https://a",
+             "emoji": ":book:", "type": "link"},
+            {"id": "Bk2", "channel_id": "C1", "title": "Docs", "link": null,
+             "emoji": null, "type": "folder"},
+        ]))
+        .unwrap()
+    }
+
+    fn spec(value: serde_json::Value) -> BookmarkSpec {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_plan_add_and_skip() {
+        let existing = existing();
+        let new = spec(json!({"title": "Wiki", "link": "https://w"}));
+        assert_eq!(plan(&new, &existing), Step::Add { link: "https://w" });
+        let no_link = spec(json!({"title": "Wiki"}));
+        assert_eq!(plan(&no_link, &existing), Step::Skip { old: None });
+    }
+
+    #[test]
+    fn test_plan_update_or_keep() {
+        let existing = existing();
+        let runbook = &existing[0];
+        let same = spec(json!({"title": "Runbook", "link": "https://a", "emoji": "book"}));
+        assert_eq!(plan(&same, &existing), Step::Keep { old: runbook });
+        // An omitted link or emoji leaves the current one alone
+        let bare = spec(json!({"title": "Runbook"}));
+        assert_eq!(plan(&bare, &existing), Step::Keep { old: runbook });
+        let moved = spec(json!({"title": "Runbook", "link": "https://b"}));
+        assert_eq!(plan(&moved, &existing), Step::Update { old: runbook });
+        let emoji = spec(json!({"title": "Runbook", "emoji": ":fire:"}));
+        assert_eq!(plan(&emoji, &existing), Step::Update { old: runbook });
+    }
+
+    #[test]
+    fn test_plan_type_change() {
+        let existing = existing();
+        let retyped = spec(json!({"title": "Runbook", "type": "folder"}));
+        assert_eq!(
+            plan(&retyped, &existing),
+            Step::Replace {
+                old: &existing[0],
+                link: "https://a"
+            }
+        );
+        let folder = spec(json!({"title": "Docs"}));
+        assert_eq!(
+            plan(&folder, &existing),
+            Step::Skip {
+                old: Some(&existing[1])
+            }
+        );
+    }
+
+    #[test]
+    fn test_list_output_round_trips() {
+        let existing = existing();
+        let listed = serde_json::to_value(&existing).unwrap();
+        let specs: Vec<BookmarkSpec> = serde_json::from_value(listed).unwrap();
+        for spec in &specs {
+            assert!(matches!(plan(spec, &existing), Step::Keep { .. }));
+        }
+        assert!(undeclared(&specs, &existing).is_empty());
+    }
+
+    #[test]
+    fn test_undeclared() {
+        let existing = existing();
+        let specs = vec![spec(json!({"title": "Docs"}))];
+        let pruned: Vec<&str> = undeclared(&specs, &existing)
+            .iter()
+            .map(|b| b.id.as_str())
+            .collect();
+        assert_eq!(pruned, ["Bk1"]);
+    }
+
+    #[test]
+    fn test_action_serializes_lowercase() {
+        assert_eq!(
+            serde_json::to_value(BookmarkAction::Replaced).unwrap(),
+            "replaced"
+        );
+    }
+}
@@ -1,11 +1,12 @@
-use crate::client::Client;
-use crate::error::Result;
+use crate::client::{Client, USER_LOOKUP_CONCURRENCY, retry_rate_limited};
+use crate::error::{Result, SlackCliError};
 use crate::output::{HumanReadable, Output};
-use crate::timeparse::parse_time_expr;
+use crate::timeparse::{parse_slack_ts, parse_time_expr};
 use chrono::{DateTime, Utc};
 use colored::Colorize;
-use futures::TryStreamExt;
-use serde::Serialize;
+use futures::{StreamExt, TryStreamExt};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use slack_morphism::prelude::*;
 use std::collections::HashMap;
 
@@ -18,6 +19,9 @@ pub struct ChannelInfo {
     pub num_members: Option<u64>,
     pub is_private: bool,
     pub is_archived: bool,
+    pub is_shared: bool,
+    pub created: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
 }
 
 impl HumanReadable for ChannelInfo {
@@ -28,19 +32,38 @@ impl HumanReadable for ChannelInfo {
         } else {
             String::new()
         };
+        let shared = if self.is_shared {
+            " (shared)".cyan().to_string()
+        } else {
+            String::new()
+        };
         let members = self
             .num_members
             .map(|n| format!(" ({} members)", n))
             .unwrap_or_default();
 
         println!(
-            "{}{}{}{}",
+            "{}{}{}{}{}",
             prefix,
             self.name.bold(),
             members.dimmed(),
+            shared,
             archived
         );
         println!("  {}: {}", "ID".dimmed(), self.id);
+        if let Some(created) = self.created {
+            let by = self
+                .creator
+                .as_deref()
+                .map(|c| format!(" by {}", c))
+                .unwrap_or_default();
+            println!(
+                "  {}: {}{}",
+                "Created".dimmed(),
+                created.format("%Y-%m-%d"),
+                by
+            );
+        }
         if let Some(topic) = &self.topic
             && !topic.is_empty()
         {
@@ -54,7 +77,7 @@ impl HumanReadable for ChannelInfo {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct MessageInfo {
     pub ts: String,
     pub user: Option<String>,
@@ -67,10 +90,22 @@ pub struct MessageInfo {
     pub username: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub real_name: Option<String>,
+    /// Thread replies, with `--with-replies`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replies: Option<Vec<MessageInfo>>,
 }
 
 impl HumanReadable for MessageInfo {
     fn print_human(&self) {
+        self.print_indented("");
+        for reply in self.replies.iter().flatten() {
+            reply.print_indented("    ");
+        }
+    }
+}
+
+impl MessageInfo {
+    pub fn print_indented(&self, indent: &str) {
         let time = self
             .timestamp
             .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
@@ -95,27 +130,185 @@ impl HumanReadable for MessageInfo {
             .unwrap_or_default();
 
         println!(
-            "{} {}{}{}:",
+            "{}{} {}{}{}:",
+            indent,
             time.dimmed(),
             user_display.green(),
             thread_info,
             reply_info
         );
-        println!("  {}", self.text);
+        for line in self.text.lines() {
+            println!("{}  {}", indent, line);
+        }
         println!();
     }
 }
 
-#[derive(Debug, Serialize)]
+/// A message with every field Slack returns for it (blocks, attachments, files, edits,
+/// reactions...), for `messages get` and `--full`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FullMessage {
+    pub ts: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edited: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<serde_json::Value>,
+    /// Any other field Slack sent
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl HumanReadable for FullMessage {
+    fn print_human(&self) {
+        let time = parse_slack_ts(&self.ts)
+            .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|_| self.ts.clone());
+        let author = self
+            .user
+            .as_deref()
+            .or(self.bot_id.as_deref())
+            .unwrap_or("unknown");
+        let subtype = self
+            .subtype
+            .as_ref()
+            .map(|s| format!(" [{}]", s).yellow().to_string())
+            .unwrap_or_default();
+
+        println!(
+            "{} {}{} {}",
+            time.dimmed(),
+            author.green(),
+            subtype,
+            self.ts.dimmed()
+        );
+        for line in self.text.lines() {
+            println!("  {}", line);
+        }
+        if let Some(thread_ts) = self.thread_ts.as_ref().filter(|t| **t != self.ts) {
+            println!("  {} {}", "in thread".dimmed(), thread_ts);
+        }
+        if let Some(count) = self.reply_count.filter(|&c| c > 0) {
+            println!("  {}", format!("[{} replies]", count).cyan());
+        }
+        if let Some(edited) = &self.edited {
+            let when = edited["ts"]
+                .as_str()
+                .and_then(|ts| parse_slack_ts(ts).ok())
+                .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                .unwrap_or_default();
+            println!("  {} {}", "edited".dimmed(), when.dimmed());
+        }
+        for file in &self.files {
+            println!(
+                "  {} {} {}",
+                "file".dimmed(),
+                file["name"].as_str().unwrap_or_default(),
+                file["id"].as_str().unwrap_or_default().dimmed()
+            );
+        }
+        for attachment in &self.attachments {
+            let title = attachment["title"]
+                .as_str()
+                .or(attachment["fallback"].as_str())
+                .unwrap_or_default();
+            println!("  {} {}", "attachment".dimmed(), title);
+        }
+        if !self.blocks.is_empty() {
+            println!("  {}", format!("{} blocks", self.blocks.len()).dimmed());
+        }
+        if !self.reactions.is_empty() {
+            let reactions: Vec<String> = self
+                .reactions
+                .iter()
+                .map(|r| {
+                    format!(
+                        ":{}: {}",
+                        r["name"].as_str().unwrap_or_default(),
+                        r["count"].as_u64().unwrap_or(0)
+                    )
+                })
+                .collect();
+            println!("  {}", reactions.join("  "));
+        }
+        println!();
+    }
+}
+
+/// Parse the `messages` array of a raw `conversations.history`/`conversations.replies` response.
+pub fn parse_full_messages(response: &serde_json::Value) -> Result<Vec<FullMessage>> {
+    serde_json::from_value(response["messages"].clone())
+        .map_err(|e| SlackCliError::Api(format!("Unexpected messages response: {}", e)))
+}
+
+#[derive(Debug, Default, Serialize)]
 pub struct MemberInfo {
     pub id: String,
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub real_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tz: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_bot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence: Option<String>,
 }
 
 impl HumanReadable for MemberInfo {
     fn print_human(&self) {
         let display = self.name.as_deref().unwrap_or(&self.id);
-        println!("  @{}", display);
+        let flag = if self.deleted == Some(true) {
+            "deleted".red().to_string()
+        } else if self.is_bot == Some(true) {
+            "bot".cyan().to_string()
+        } else {
+            String::new()
+        };
+        let presence = match self.presence.as_deref() {
+            Some("active") => "online".green().to_string(),
+            Some(_) => "away  ".dimmed().to_string(),
+            None => String::new(),
+        };
+
+        // Pad before coloring so ANSI codes don't skew the column widths.
+        let name = format!("@{:<20}", display);
+        let id = format!("{:<11}", self.id);
+        let real_name = format!("{:<24}", self.real_name.as_deref().unwrap_or(""));
+        let title = format!("{:<24}", self.title.as_deref().unwrap_or(""));
+        let tz = format!("{:<20}", self.tz.as_deref().unwrap_or(""));
+
+        println!(
+            "  {} {} {} {} {} {} {}",
+            name.green(),
+            id.dimmed(),
+            real_name,
+            title.dimmed(),
+            tz.dimmed(),
+            presence,
+            flag
+        );
     }
 }
 
@@ -145,12 +338,99 @@ impl HumanReadable for PinnedMessage {
     }
 }
 
-pub async fn list(client: &Client, output: &Output, limit: Option<u16>) -> Result<()> {
+/// Sort order for `channels list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelSort {
+    Name,
+    Members,
+    Created,
+}
+
+impl ChannelSort {
+    pub fn parse(s: &str) -> std::result::Result<Self, String> {
+        match s.to_lowercase().as_str() {
+            "name" => Ok(Self::Name),
+            "members" => Ok(Self::Members),
+            "created" => Ok(Self::Created),
+            other => Err(format!(
+                "unknown sort key: {other} (use name, members or created)"
+            )),
+        }
+    }
+}
+
+/// Parse a conversation type name as accepted by `--types`.
+pub fn parse_conversation_type(s: &str) -> std::result::Result<SlackConversationType, String> {
+    match s.to_lowercase().as_str() {
+        "public" => Ok(SlackConversationType::Public),
+        "private" => Ok(SlackConversationType::Private),
+        "mpim" => Ok(SlackConversationType::Mpim),
+        "im" => Ok(SlackConversationType::Im),
+        other => Err(format!(
+            "unknown conversation type: {other} (use public, private, mpim or im)"
+        )),
+    }
+}
+
+/// Filters and ordering applied by `channels list`.
+#[derive(Debug, Default)]
+pub struct ListFilters {
+    /// Conversation types to request (empty = public channels only)
+    pub types: Vec<SlackConversationType>,
+    pub include_archived: bool,
+    /// Regex matched against the channel name
+    pub name: Option<String>,
+    pub min_members: Option<u64>,
+    /// Time expression (see `timeparse`) for the earliest creation date
+    pub created_after: Option<String>,
+    pub sort: Option<ChannelSort>,
+}
+
+fn channel_from_slack(c: SlackChannelInfo) -> ChannelInfo {
+    ChannelInfo {
+        id: c.id.0,
+        name: c.name.unwrap_or_default(),
+        topic: c.topic.map(|t| t.value),
+        purpose: c.purpose.map(|p| p.value),
+        num_members: c.num_members,
+        is_private: c.flags.is_private.unwrap_or(false),
+        is_archived: c.flags.is_archived.unwrap_or(false),
+        is_shared: c.flags.is_shared.unwrap_or(false),
+        created: Some(c.created.0),
+        creator: c.creator.map(|u| u.0),
+    }
+}
+
+pub async fn list(
+    client: &Client,
+    output: &Output,
+    limit: Option<u16>,
+    filters: &ListFilters,
+) -> Result<()> {
     let session = client.session();
 
-    let request = SlackApiConversationsListRequest::new()
-        .with_exclude_archived(true)
+    let name_re = filters
+        .name
+        .as_deref()
+        .map(Regex::new)
+        .transpose()
+        .map_err(|e| SlackCliError::Config(format!("Invalid --name regex: {e}")))?;
+    let created_after = filters
+        .created_after
+        .as_deref()
+        .map(|s| {
+            parse_time_expr(s)
+                .map_err(SlackCliError::Api)
+                .and_then(|ts| parse_slack_ts(&ts).map_err(SlackCliError::Api))
+        })
+        .transpose()?;
+
+    let mut request = SlackApiConversationsListRequest::new()
+        .with_exclude_archived(!filters.include_archived)
         .with_limit(limit.unwrap_or(200));
+    if !filters.types.is_empty() {
+        request = request.with_types(filters.types.clone());
+    }
 
     let mut all_channels: Vec<SlackChannelInfo> = Vec::new();
     let scroller = request.scroller();
@@ -160,19 +440,27 @@ pub async fn list(client: &Client, output: &Output, limit: Option<u16>) -> Resul
         all_channels.extend(batch);
     }
 
-    let channels: Vec<ChannelInfo> = all_channels
+    let mut channels: Vec<ChannelInfo> = all_channels
         .into_iter()
-        .map(|c| ChannelInfo {
-            id: c.id.0,
-            name: c.name.unwrap_or_default(),
-            topic: c.topic.map(|t| t.value),
-            purpose: c.purpose.map(|p| p.value),
-            num_members: c.num_members,
-            is_private: c.flags.is_private.unwrap_or(false),
-            is_archived: c.flags.is_archived.unwrap_or(false),
+        .map(channel_from_slack)
+        .filter(|c| name_re.as_ref().is_none_or(|re| re.is_match(&c.name)))
+        .filter(|c| {
+            filters
+                .min_members
+                .is_none_or(|min| c.num_members.unwrap_or(0) >= min)
         })
+        .filter(|c| created_after.is_none_or(|after| c.created.is_some_and(|t| t >= after)))
         .collect();
 
+    match filters.sort {
+        Some(ChannelSort::Name) => channels.sort_by(|a, b| a.name.cmp(&b.name)),
+        Some(ChannelSort::Members) => {
+            channels.sort_by_key(|c| std::cmp::Reverse(c.num_members.unwrap_or(0)))
+        }
+        Some(ChannelSort::Created) => channels.sort_by_key(|c| std::cmp::Reverse(c.created)),
+        None => {}
+    }
+
     output.print_list(&channels, "Channels");
 
     Ok(())
@@ -185,43 +473,94 @@ pub async fn info(client: &Client, output: &Output, channel: &str) -> Result<()>
     let request = SlackApiConversationsInfoRequest::new(channel_id);
     let response = session.conversations_info(&request).await?;
 
-    let c = response.channel;
-    let info = ChannelInfo {
-        id: c.id.0,
-        name: c.name.unwrap_or_default(),
-        topic: c.topic.map(|t| t.value),
-        purpose: c.purpose.map(|p| p.value),
-        num_members: c.num_members,
-        is_private: c.flags.is_private.unwrap_or(false),
-        is_archived: c.flags.is_archived.unwrap_or(false),
-    };
+    let info = channel_from_slack(response.channel);
 
     output.print(&info);
 
     Ok(())
 }
 
+/// Options for `channels history` and `dms history`.
+#[derive(Debug, Default)]
+pub struct HistoryOptions {
+    pub limit: Option<u16>,
+    pub after: Option<String>,
+    pub before: Option<String>,
+    /// Resolve user IDs to usernames and real names
+    pub enrich: bool,
+    /// Fetch thread replies and nest them under their parent
+    pub with_replies: bool,
+    /// Return complete messages (`FullMessage`) instead of the trimmed `MessageInfo`
+    pub full: bool,
+}
+
+/// Max in-flight `conversations.replies` calls for `--with-replies`.
+const REPLY_FETCH_CONCURRENCY: usize = 8;
+
 pub async fn history(
     client: &Client,
     output: &Output,
     channel: &str,
-    limit: Option<u16>,
-    after: Option<&str>,
-    before: Option<&str>,
-    enrich: bool,
+    opts: &HistoryOptions,
 ) -> Result<()> {
-    let session = client.session();
     let channel_id = client.resolve_channel(channel).await?;
+    let title = format!("Messages in {}", channel);
+
+    if opts.full {
+        let messages = fetch_full_history(client, &channel_id, opts).await?;
+        output.print_list(&messages, &title);
+    } else {
+        let messages = fetch_history(client, &channel_id, opts).await?;
+        output.print_list(&messages, &title);
+    }
+
+    Ok(())
+}
+
+/// Fetch a page of history with every message field (raw `conversations.history`,
+/// since slack-morphism's model drops some of them).
+pub async fn fetch_full_history(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    opts: &HistoryOptions,
+) -> Result<Vec<FullMessage>> {
+    let limit = opts.limit.unwrap_or(20).to_string();
+    let mut params = vec![("channel", channel_id.0.clone()), ("limit", limit)];
+    if let Some(after) = &opts.after {
+        params.push((
+            "oldest",
+            parse_time_expr(after).map_err(SlackCliError::Api)?,
+        ));
+    }
+    if let Some(before) = &opts.before {
+        params.push((
+            "latest",
+            parse_time_expr(before).map_err(SlackCliError::Api)?,
+        ));
+    }
+    let params: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+
+    let response = client.api_get("conversations.history", &params).await?;
+    parse_full_messages(&response)
+}
+
+/// Fetch a page of history, with thread replies and user names if requested.
+pub async fn fetch_history(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    opts: &HistoryOptions,
+) -> Result<Vec<MessageInfo>> {
+    let session = client.session();
 
     let mut request = SlackApiConversationsHistoryRequest::new()
-        .with_channel(channel_id)
-        .with_limit(limit.unwrap_or(20));
+        .with_channel(channel_id.clone())
+        .with_limit(opts.limit.unwrap_or(20));
 
-    if let Some(after) = after {
+    if let Some(after) = &opts.after {
         let ts = parse_time_expr(after).map_err(crate::error::SlackCliError::Api)?;
         request = request.with_oldest(SlackTs::new(ts));
     }
-    if let Some(before) = before {
+    if let Some(before) = &opts.before {
         let ts = parse_time_expr(before).map_err(crate::error::SlackCliError::Api)?;
         request = request.with_latest(SlackTs::new(ts));
     }
@@ -231,72 +570,131 @@ pub async fn history(
     let mut messages: Vec<MessageInfo> = response
         .messages
         .into_iter()
-        .map(|m| {
-            let ts_float: f64 = m.origin.ts.0.parse().unwrap_or(0.0);
-            let timestamp = DateTime::from_timestamp(ts_float as i64, 0);
-
-            MessageInfo {
-                ts: m.origin.ts.0,
-                user: m.sender.user.map(|u| u.0),
-                text: m.content.text.unwrap_or_default(),
-                timestamp,
-                thread_ts: m.origin.thread_ts.map(|t| t.0),
-                reply_count: m.parent.reply_count.map(|c| c as u64),
-                latest_reply: m.parent.latest_reply.map(|t| t.0),
-                username: None,
-                real_name: None,
-            }
-        })
+        .map(message_from_slack)
         .collect();
 
-    if enrich {
-        enrich_messages(client, &mut messages).await?;
+    if opts.with_replies {
+        attach_replies(client, channel_id, &mut messages).await?;
+    }
+    if opts.enrich {
+        enrich_messages(client, &mut messages).await;
     }
 
-    output.print_list(&messages, &format!("Messages in {}", channel));
+    Ok(messages)
+}
+
+pub fn message_from_slack(m: SlackHistoryMessage) -> MessageInfo {
+    let timestamp = parse_slack_ts(&m.origin.ts.0).ok();
+
+    MessageInfo {
+        ts: m.origin.ts.0,
+        user: m.sender.user.map(|u| u.0),
+        text: m.content.text.unwrap_or_default(),
+        timestamp,
+        thread_ts: m.origin.thread_ts.map(|t| t.0),
+        reply_count: m.parent.reply_count.map(|c| c as u64),
+        latest_reply: m.parent.latest_reply.map(|t| t.0),
+        username: None,
+        real_name: None,
+        replies: None,
+    }
+}
+
+/// Fetch the replies of every threaded message concurrently and nest them under their parent.
+pub async fn attach_replies(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    messages: &mut [MessageInfo],
+) -> Result<()> {
+    let threads: Vec<(usize, SlackTs)> = messages
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| m.reply_count.is_some_and(|c| c > 0))
+        .map(|(i, m)| (i, SlackTs::new(m.ts.clone())))
+        .collect();
+
+    let results: Vec<Result<(usize, Vec<MessageInfo>)>> = futures::stream::iter(threads)
+        .map(|(i, thread_ts)| async move {
+            let session = client.session();
+            let request =
+                SlackApiConversationsRepliesRequest::new(channel_id.clone(), thread_ts.clone())
+                    .with_limit(200);
+            let scroller = request.scroller();
+            let mut stream = scroller.to_items_stream(&session);
+            let mut replies = Vec::new();
+            while let Some(batch) = stream.try_next().await? {
+                replies.extend(
+                    batch
+                        .into_iter()
+                        .filter(|m| m.origin.ts != thread_ts)
+                        .map(message_from_slack),
+                );
+            }
+            Ok((i, replies))
+        })
+        .buffer_unordered(REPLY_FETCH_CONCURRENCY)
+        .collect()
+        .await;
+
+    for result in results {
+        let (i, replies) = result?;
+        messages[i].replies = Some(replies);
+    }
 
     Ok(())
 }
 
 /// Batch-fetch user info for all unique user IDs in messages and populate username/real_name.
-pub async fn enrich_messages(client: &Client, messages: &mut [MessageInfo]) -> Result<()> {
+/// Users that still can't be looked up after rate-limit retries keep just their ID.
+pub async fn enrich_messages(client: &Client, messages: &mut [MessageInfo]) {
     let user_ids: Vec<String> = messages
         .iter()
+        .flat_map(|m| std::iter::once(m).chain(m.replies.iter().flatten()))
         .filter_map(|m| m.user.clone())
         .collect::<std::collections::HashSet<_>>()
         .into_iter()
         .collect();
 
     if user_ids.is_empty() {
-        return Ok(());
+        return;
     }
 
-    let session = client.session();
-    let mut user_map: HashMap<String, (String, Option<String>)> = HashMap::new();
-
-    for uid in &user_ids {
-        let user_id = SlackUserId::new(uid.clone());
-        let request = SlackApiUsersInfoRequest::new(user_id);
-        match session.users_info(&request).await {
-            Ok(resp) => {
-                let name = resp.user.name.unwrap_or_default();
-                let real_name = resp.user.profile.and_then(|p| p.real_name);
-                user_map.insert(uid.clone(), (name, real_name));
-            }
-            Err(_) => continue, // skip users we can't resolve
-        }
-    }
+    let user_map: HashMap<String, (String, Option<String>)> = client
+        .lookup_users(&user_ids)
+        .await
+        .into_iter()
+        .filter_map(|(uid, user)| {
+            let user = user.ok()?;
+            let real_name = user.profile.and_then(|p| p.real_name);
+            Some((uid, (user.name.unwrap_or_default(), real_name)))
+        })
+        .collect();
 
-    for msg in messages.iter_mut() {
+    let apply = |msg: &mut MessageInfo| {
         if let Some(uid) = &msg.user
             && let Some((uname, rname)) = user_map.get(uid)
         {
             msg.username = Some(uname.clone());
             msg.real_name = rname.clone();
         }
+    };
+    for msg in messages.iter_mut() {
+        apply(msg);
+        for reply in msg.replies.iter_mut().flatten() {
+            apply(reply);
+        }
     }
+}
 
-    Ok(())
+/// Options for `channels members`.
+#[derive(Debug, Default)]
+pub struct MemberOptions {
+    /// Look up profile details for each member
+    pub enrich: bool,
+    /// Look up online/away presence for each member
+    pub presence: bool,
+    pub exclude_bots: bool,
+    pub exclude_deleted: bool,
 }
 
 pub async fn members(
@@ -304,6 +702,7 @@ pub async fn members(
     output: &Output,
     channel: &str,
     limit: Option<u16>,
+    opts: &MemberOptions,
 ) -> Result<()> {
     let session = client.session();
     let channel_id = client.resolve_channel(channel).await?;
@@ -320,19 +719,112 @@ pub async fn members(
         all_members.extend(batch);
     }
 
-    let members: Vec<MemberInfo> = all_members
+    let mut members: Vec<MemberInfo> = all_members
         .into_iter()
         .map(|id| MemberInfo {
             id: id.0,
-            name: None,
+            ..Default::default()
         })
         .collect();
 
+    // Filtering on bot/deleted flags needs the user profiles
+    if opts.enrich || opts.exclude_bots || opts.exclude_deleted {
+        output.status(&format!("Resolving {} members...", members.len()));
+        let failed = enrich_members(client, &mut members).await;
+        if failed > 0 {
+            output.status(&format!(
+                "{} members couldn't be looked up; they are shown without profile details{}",
+                failed,
+                if opts.exclude_bots || opts.exclude_deleted {
+                    " and kept by --exclude-bots/--exclude-deleted"
+                } else {
+                    ""
+                }
+            ));
+        }
+    }
+    if opts.exclude_bots {
+        members.retain(|m| m.is_bot != Some(true));
+    }
+    if opts.exclude_deleted {
+        members.retain(|m| m.deleted != Some(true));
+    }
+    if opts.presence {
+        output.status("Checking presence...");
+        let failed = fetch_member_presence(client, &mut members).await;
+        if failed > 0 {
+            output.status(&format!("Presence unknown for {} members", failed));
+        }
+    }
+
     output.print_list(&members, &format!("Members of {}", channel));
 
     Ok(())
 }
 
+/// Fill in profile fields. Returns how many members couldn't be looked up; those are
+/// left as-is.
+async fn enrich_members(client: &Client, members: &mut [MemberInfo]) -> usize {
+    let ids: Vec<String> = members.iter().map(|m| m.id.clone()).collect();
+    let mut users = client.lookup_users(&ids).await;
+
+    let mut failed = 0;
+    for member in members.iter_mut() {
+        let Some(Ok(user)) = users.remove(&member.id) else {
+            failed += 1;
+            continue;
+        };
+        let profile = user.profile.as_ref();
+        member.name = user.name.clone();
+        member.real_name = profile.and_then(|p| p.real_name.clone());
+        member.title = profile
+            .and_then(|p| p.title.clone())
+            .filter(|t| !t.is_empty());
+        member.tz = user.tz.clone();
+        member.is_bot = Some(user.flags.is_bot.unwrap_or(false));
+        member.deleted = Some(user.deleted.unwrap_or(false));
+    }
+    failed
+}
+
+/// Fill in presence with bounded concurrency. Returns how many lookups failed.
+async fn fetch_member_presence(client: &Client, members: &mut [MemberInfo]) -> usize {
+    let lookups = futures::stream::iter(members.iter_mut().map(|member| async move {
+        let session = client.session();
+        let request = SlackApiUsersGetPresenceRequest::new(SlackUserId::new(member.id.clone()));
+        let presence = retry_rate_limited(|| async {
+            Ok(session.users_get_presence(&request).await?.presence)
+        })
+        .await?;
+        member.presence = Some(presence);
+        Ok(())
+    }))
+    .buffer_unordered(USER_LOOKUP_CONCURRENCY);
+    let results: Vec<Result<()>> = lookups.collect().await;
+    results.iter().filter(|r| r.is_err()).count()
+}
+
+/// Whether a user is a member of a channel (`conversations.members`)
+pub async fn is_member(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    user_id: &SlackUserId,
+) -> Result<bool> {
+    let session = client.session();
+    let request = SlackApiConversationsMembersRequest::new()
+        .with_channel(channel_id.clone())
+        .with_limit(200);
+    let scroller = request.scroller();
+    let mut stream = scroller.to_items_stream(&session);
+
+    while let Some(batch) = stream.try_next().await? {
+        if batch.contains(user_id) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
 /// List pinned messages in a channel
 pub async fn pins(client: &Client, output: &Output, channel: &str) -> Result<()> {
     let session = client.session();
@@ -389,3 +881,201 @@ pub async fn leave(client: &Client, output: &Output, channel: &str) -> Result<()
 
     Ok(())
 }
+
+/// Create a public or private channel
+pub async fn create(client: &Client, output: &Output, name: &str, private: bool) -> Result<()> {
+    let session = client.session();
+
+    let request =
+        SlackApiConversationsCreateRequest::new(name.to_string()).with_is_private(private);
+    let response = session.conversations_create(&request).await?;
+
+    let info = channel_from_slack(response.channel);
+
+    output.print(&info);
+    output.success(&format!("Created #{}", info.name));
+
+    Ok(())
+}
+
+/// Archive a channel
+pub async fn archive(client: &Client, output: &Output, channel: &str) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let request = SlackApiConversationsArchiveRequest::new(channel_id);
+    session.conversations_archive(&request).await?;
+
+    output.success(&format!("Archived #{}", channel));
+
+    Ok(())
+}
+
+/// Unarchive a channel
+pub async fn unarchive(client: &Client, output: &Output, channel: &str) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_archived_channel(channel).await?;
+
+    let request = SlackApiConversationsUnarchiveRequest::new(channel_id);
+    session.conversations_unarchive(&request).await?;
+
+    output.success(&format!("Unarchived #{}", channel));
+
+    Ok(())
+}
+
+/// Rename a channel
+pub async fn rename(client: &Client, output: &Output, channel: &str, name: &str) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let request = SlackApiConversationsRenameRequest::new(channel_id, name.to_string());
+    let response = session.conversations_rename(&request).await?;
+
+    let info = channel_from_slack(response.channel);
+
+    output.print(&info);
+    output.success(&format!("Renamed #{} to #{}", channel, info.name));
+
+    Ok(())
+}
+
+/// Set a channel's topic
+pub async fn set_topic(client: &Client, output: &Output, channel: &str, topic: &str) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let request = SlackApiConversationsSetTopicRequest::new(channel_id, topic.to_string());
+    session.conversations_set_topic(&request).await?;
+
+    output.success(&format!("Topic of #{} set to: {}", channel, topic));
+
+    Ok(())
+}
+
+/// Set a channel's purpose
+pub async fn set_purpose(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    purpose: &str,
+) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let request = SlackApiConversationsSetPurposeRequest::new(channel_id, purpose.to_string());
+    session.conversations_set_purpose(&request).await?;
+
+    output.success(&format!("Purpose of #{} set to: {}", channel, purpose));
+
+    Ok(())
+}
+
+/// Invite users to a channel
+pub async fn invite(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    users: &[String],
+) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let mut user_ids = Vec::with_capacity(users.len());
+    for user in users {
+        user_ids.push(client.resolve_user(user).await?);
+    }
+
+    let request = SlackApiConversationsInviteRequest::new(channel_id, user_ids);
+    session.conversations_invite(&request).await?;
+
+    output.success(&format!("Invited {} to #{}", users.join(", "), channel));
+
+    Ok(())
+}
+
+/// Remove a user from a channel
+pub async fn kick(client: &Client, output: &Output, channel: &str, user: &str) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+    let user_id = client.resolve_user(user).await?;
+
+    let request = SlackApiConversationsKickRequest::new(channel_id, user_id);
+    session.conversations_kick(&request).await?;
+
+    output.success(&format!("Removed {} from #{}", user, channel));
+
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+pub struct MarkedRead {
+    pub channel: String,
+    pub name: String,
+    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl HumanReadable for MarkedRead {
+    fn print_human(&self) {
+        match &self.error {
+            Some(error) => println!("{} {}: {}", "✗".red(), self.name.bold(), error),
+            None => println!(
+                "{} {} {}",
+                "✓".green(),
+                self.name.bold(),
+                format!("read up to {}", self.ts.as_deref().unwrap_or("-")).dimmed()
+            ),
+        }
+    }
+}
+
+/// Latest message ts in a conversation, if it has any messages.
+pub async fn latest_ts(client: &Client, channel_id: &SlackChannelId) -> Result<Option<SlackTs>> {
+    let session = client.session();
+    let request = SlackApiConversationsHistoryRequest::new()
+        .with_channel(channel_id.clone())
+        .with_limit(1);
+    let response = session.conversations_history(&request).await?;
+    Ok(response.messages.into_iter().next().map(|m| m.origin.ts))
+}
+
+/// Move the read cursor of a conversation to `ts` (`conversations.mark`).
+pub async fn mark_read(client: &Client, channel_id: &SlackChannelId, ts: &SlackTs) -> Result<()> {
+    client
+        .api_post(
+            "conversations.mark",
+            &serde_json::json!({ "channel": channel_id.0, "ts": ts.0 }),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Mark a channel or DM as read up to `ts`, or up to its latest message
+pub async fn mark(client: &Client, output: &Output, channel: &str, ts: Option<&str>) -> Result<()> {
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let ts = match ts {
+        Some(ts) => SlackTs::new(ts.to_string()),
+        None => match latest_ts(client, &channel_id).await? {
+            Some(ts) => ts,
+            None => {
+                output.success(&format!("#{} has no messages to mark", channel));
+                return Ok(());
+            }
+        },
+    };
+
+    mark_read(client, &channel_id, &ts).await?;
+
+    output.print(&MarkedRead {
+        channel: channel_id.0,
+        name: channel.to_string(),
+        ts: Some(ts.0),
+        error: None,
+    });
+    output.success(&format!("Marked #{} as read", channel));
+
+    Ok(())
+}
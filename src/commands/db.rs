@@ -0,0 +1,126 @@
+use crate::error::{Result, SlackCliError};
+use crate::mirror::Mirror;
+use crate::output::{HumanReadable, Output};
+use crate::timeparse::parse_time_expr;
+use colored::Colorize;
+use rusqlite::types::ValueRef;
+use serde::Serialize;
+use std::path::Path;
+
+/// One result row of `db query`, keyed by column name.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct QueryRow(serde_json::Map<String, serde_json::Value>);
+
+impl HumanReadable for QueryRow {
+    fn print_human(&self) {
+        for (column, value) in &self.0 {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            println!("  {}: {}", column.dimmed(), value);
+        }
+        println!();
+    }
+}
+
+/// Run a read-only SQL query against the local mirror.
+pub fn query(output: &Output, db: &Path, sql: &str) -> Result<()> {
+    let mirror = Mirror::open_readonly(db)?;
+    let mut stmt = mirror.connection().prepare(sql)?;
+    let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+
+    let mut rows = stmt.query([])?;
+    let mut results = Vec::new();
+    while let Some(row) = rows.next()? {
+        let mut map = serde_json::Map::new();
+        for (i, column) in columns.iter().enumerate() {
+            let value = match row.get_ref(i)? {
+                ValueRef::Null => serde_json::Value::Null,
+                ValueRef::Integer(n) => n.into(),
+                ValueRef::Real(f) => f.into(),
+                ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned().into(),
+                ValueRef::Blob(b) => format!("<{} bytes>", b.len()).into(),
+            };
+            map.insert(column.clone(), value);
+        }
+        results.push(QueryRow(map));
+    }
+
+    output.print_list(&results, "Query results");
+
+    Ok(())
+}
+
+/// `channels history --offline`
+pub fn history(
+    output: &Output,
+    db: &Path,
+    channel: &str,
+    limit: Option<u16>,
+    after: Option<&str>,
+    before: Option<&str>,
+    enrich: bool,
+) -> Result<()> {
+    let mirror = Mirror::open_readonly(db)?;
+    let channel_id = mirror.resolve_channel(channel)?;
+
+    let oldest = after
+        .map(parse_time_expr)
+        .transpose()
+        .map_err(SlackCliError::Api)?;
+    let latest = before
+        .map(parse_time_expr)
+        .transpose()
+        .map_err(SlackCliError::Api)?;
+
+    let mut messages = mirror.history(
+        &channel_id,
+        limit.unwrap_or(20),
+        oldest.as_deref(),
+        latest.as_deref(),
+    )?;
+    if !enrich {
+        for m in &mut messages {
+            m.username = None;
+            m.real_name = None;
+        }
+    }
+
+    output.print_list(&messages, &format!("Messages in {} (offline)", channel));
+
+    Ok(())
+}
+
+/// `messages replies --offline`
+pub fn replies(
+    output: &Output,
+    db: &Path,
+    channel: &str,
+    thread_ts: &str,
+    limit: Option<u16>,
+) -> Result<()> {
+    let mirror = Mirror::open_readonly(db)?;
+    let channel_id = mirror.resolve_channel(channel)?;
+
+    let replies = mirror.replies(&channel_id, thread_ts, limit.unwrap_or(100))?;
+
+    output.print_list(
+        &replies,
+        &format!("Thread replies in {} (offline)", channel),
+    );
+
+    Ok(())
+}
+
+/// `users search --offline`
+pub fn search_users(output: &Output, db: &Path, query: &str) -> Result<()> {
+    let mirror = Mirror::open_readonly(db)?;
+
+    let users = mirror.search_users(query)?;
+
+    output.print_list(&users, &format!("Users matching '{query}' (offline)"));
+
+    Ok(())
+}
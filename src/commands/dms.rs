@@ -1,9 +1,8 @@
 use crate::client::Client;
-use crate::commands::channels::{MessageInfo, enrich_messages};
+use crate::commands::channels::{HistoryOptions, fetch_full_history, fetch_history};
+use crate::commands::messages::{MessageBody, PostOptions, Posted, post_body};
 use crate::error::Result;
 use crate::output::{HumanReadable, Output};
-use crate::timeparse::parse_time_expr;
-use chrono::DateTime;
 use colored::Colorize;
 use serde::Serialize;
 use slack_morphism::prelude::*;
@@ -35,6 +34,11 @@ pub struct SentDm {
     pub ts: String,
     pub user: String,
     pub text: String,
+    /// Timestamps of the thread replies carrying the rest of a split body
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<String>,
+    #[serde(flatten)]
+    pub options: PostOptions,
 }
 
 impl HumanReadable for SentDm {
@@ -42,6 +46,9 @@ impl HumanReadable for SentDm {
         println!("{} to {}", "DM sent".green(), self.user);
         println!("  channel: {}", self.channel.dimmed());
         println!("  ts: {}", self.ts.dimmed());
+        if !self.parts.is_empty() {
+            println!("  continued in {} thread replies", self.parts.len());
+        }
     }
 }
 
@@ -78,61 +85,32 @@ pub async fn history(
     client: &Client,
     output: &Output,
     dm_channel: &str,
-    limit: Option<u16>,
-    after: Option<&str>,
-    before: Option<&str>,
-    enrich: bool,
+    opts: &HistoryOptions,
 ) -> Result<()> {
-    let session = client.session();
     let channel_id = client.resolve_channel(dm_channel).await?;
-
-    let mut request = SlackApiConversationsHistoryRequest::new()
-        .with_channel(channel_id)
-        .with_limit(limit.unwrap_or(20));
-
-    if let Some(after) = after {
-        let ts = parse_time_expr(after).map_err(crate::error::SlackCliError::Api)?;
-        request = request.with_oldest(SlackTs::new(ts));
-    }
-    if let Some(before) = before {
-        let ts = parse_time_expr(before).map_err(crate::error::SlackCliError::Api)?;
-        request = request.with_latest(SlackTs::new(ts));
-    }
-
-    let response = session.conversations_history(&request).await?;
-
-    let mut messages: Vec<MessageInfo> = response
-        .messages
-        .into_iter()
-        .map(|m| {
-            let ts_float: f64 = m.origin.ts.0.parse().unwrap_or(0.0);
-            let timestamp = DateTime::from_timestamp(ts_float as i64, 0);
-
-            MessageInfo {
-                ts: m.origin.ts.0,
-                user: m.sender.user.map(|u| u.0),
-                text: m.content.text.unwrap_or_default(),
-                timestamp,
-                thread_ts: m.origin.thread_ts.map(|t| t.0),
-                reply_count: m.parent.reply_count.map(|c| c as u64),
-                latest_reply: m.parent.latest_reply.map(|t| t.0),
-                username: None,
-                real_name: None,
-            }
-        })
-        .collect();
-
-    if enrich {
-        enrich_messages(client, &mut messages).await?;
+    let title = format!("DM history in {}", dm_channel);
+
+    if opts.full {
+        let messages = fetch_full_history(client, &channel_id, opts).await?;
+        output.print_list(&messages, &title);
+    } else {
+        let messages = fetch_history(client, &channel_id, opts).await?;
+        output.print_list(&messages, &title);
     }
 
-    output.print_list(&messages, &format!("DM history in {}", dm_channel));
-
     Ok(())
 }
 
-/// Send a DM to a user (opens conversation first)
-pub async fn send(client: &Client, output: &Output, user: &str, text: &str) -> Result<()> {
+/// Send a DM to a user (opens conversation first). Long bodies are split into a thread,
+/// or uploaded as a snippet with `opts.snippet`.
+pub async fn send(
+    client: &Client,
+    output: &Output,
+    user: &str,
+    body: &MessageBody,
+    thread_ts: Option<&str>,
+    opts: &PostOptions,
+) -> Result<()> {
     let session = client.session();
     let user_id = SlackUserId::new(user.to_string());
 
@@ -142,19 +120,26 @@ pub async fn send(client: &Client, output: &Output, user: &str, text: &str) -> R
     let channel_id = open_response.channel.id;
 
     // Send the message
-    let content = SlackMessageContent::new().with_text(text.to_string());
-    let msg_request = SlackApiChatPostMessageRequest::new(channel_id.clone(), content);
-    let msg_response = session.chat_post_message(&msg_request).await?;
-
-    let sent = SentDm {
-        channel: channel_id.0,
-        ts: msg_response.ts.0,
-        user: user.to_string(),
-        text: text.to_string(),
-    };
-
-    output.print(&sent);
-    output.success("DM sent");
+    match post_body(client, &channel_id, body, thread_ts, opts).await? {
+        Posted::Snippet(uploaded) => {
+            output.print(&uploaded);
+            output.success("DM uploaded as a snippet");
+        }
+        Posted::Messages(mut ts) => {
+            let parts = ts.split_off(1);
+            let sent = SentDm {
+                channel: channel_id.0,
+                ts: ts.remove(0),
+                user: user.to_string(),
+                text: body.text.clone(),
+                parts,
+                options: opts.clone(),
+            };
+
+            output.print(&sent);
+            output.success("DM sent");
+        }
+    }
 
     Ok(())
 }
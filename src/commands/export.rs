@@ -0,0 +1,560 @@
+use crate::client::Client;
+use crate::commands::files::fetch_file;
+use crate::error::{Result, SlackCliError};
+use crate::output::{HumanReadable, Output};
+use crate::timeparse::{parse_slack_ts, parse_time_expr};
+use colored::Colorize;
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use slack_morphism::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Progress file kept in the output directory so an interrupted export can resume.
+const STATE_FILE: &str = ".slackline-export.json";
+
+#[derive(Debug, Serialize)]
+pub struct ExportSummary {
+    pub channel: String,
+    pub name: String,
+    pub days: usize,
+    pub messages: usize,
+    pub replies: usize,
+    pub files: usize,
+}
+
+impl HumanReadable for ExportSummary {
+    fn print_human(&self) {
+        println!("#{} {}", self.name.bold(), self.channel.dimmed());
+        println!(
+            "  {} messages, {} replies over {} days, {} files",
+            self.messages, self.replies, self.days, self.files
+        );
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ExportState {
+    /// `--after` / `--before` as given, so relative ones (`30d`) can be recognised on resume
+    #[serde(default)]
+    after: Option<String>,
+    #[serde(default)]
+    before: Option<String>,
+    /// The bounds they resolved to on the first run
+    oldest: Option<String>,
+    latest: Option<String>,
+    channels: HashMap<String, ChannelProgress>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct ChannelProgress {
+    /// History has been written for everything newer than this ts
+    cursor_ts: Option<String>,
+    done: bool,
+}
+
+struct Exporter<'a> {
+    client: &'a Client,
+    output: &'a Output,
+    out: PathBuf,
+    with_files: bool,
+    state: ExportState,
+}
+
+/// Export channels in Slack's export layout: `channels.json`, `users.json` and
+/// `<channel>/<YYYY-MM-DD>.json` (UTC days) with thread replies inlined by date.
+pub async fn export(
+    client: &Client,
+    output: &Output,
+    channels: &[String],
+    out: &str,
+    after: Option<&str>,
+    before: Option<&str>,
+    with_files: bool,
+) -> Result<()> {
+    let oldest = after
+        .map(parse_time_expr)
+        .transpose()
+        .map_err(SlackCliError::Api)?;
+    let latest = before
+        .map(parse_time_expr)
+        .transpose()
+        .map_err(SlackCliError::Api)?;
+
+    let out = PathBuf::from(out);
+    std::fs::create_dir_all(&out)?;
+
+    let state = resume(load_state(&out), after, before, oldest, latest);
+
+    let channel_ids = client.resolve_channels(channels).await?;
+    let session = client.session();
+
+    output.status("Exporting users...");
+    let mut users = Vec::new();
+    let request = SlackApiUsersListRequest::new().with_limit(200);
+    let scroller = request.scroller();
+    let mut stream = scroller.to_items_stream(&session);
+    while let Some(batch) = stream.try_next().await? {
+        for user in batch {
+            users.push(to_json(&user)?);
+        }
+    }
+    write_json(&out.join("users.json"), &users)?;
+
+    output.status("Exporting channels...");
+    let mut channel_values = Vec::new();
+    let mut dirs = Vec::new();
+    for channel_id in &channel_ids {
+        let request = SlackApiConversationsInfoRequest::new(channel_id.clone());
+        let info = session.conversations_info(&request).await?.channel;
+
+        let request = SlackApiConversationsMembersRequest::new()
+            .with_channel(channel_id.clone())
+            .with_limit(200);
+        let mut members: Vec<String> = Vec::new();
+        let scroller = request.scroller();
+        let mut stream = scroller.to_items_stream(&session);
+        while let Some(batch) = stream.try_next().await? {
+            members.extend(batch.into_iter().map(|u| u.0));
+        }
+
+        let name = info.name.clone().unwrap_or_else(|| channel_id.0.clone());
+        let mut value = to_json(&info)?;
+        value["members"] = serde_json::json!(members);
+        channel_values.push(value);
+        dirs.push(name);
+    }
+    write_json(&out.join("channels.json"), &channel_values)?;
+
+    let mut exporter = Exporter {
+        client,
+        output,
+        out,
+        with_files,
+        state,
+    };
+
+    let mut summaries = Vec::new();
+    for (channel_id, name) in channel_ids.iter().zip(dirs) {
+        exporter.export_channel(channel_id, &name).await?;
+        summaries.push(summarize(&exporter.out.join(&name), &channel_id.0, &name)?);
+    }
+
+    output.print_list(
+        &summaries,
+        &format!("Exported to {}", exporter.out.display()),
+    );
+    output.success("Export complete");
+
+    Ok(())
+}
+
+impl Exporter<'_> {
+    async fn export_channel(&mut self, channel_id: &SlackChannelId, name: &str) -> Result<()> {
+        let progress = self
+            .state
+            .channels
+            .entry(channel_id.0.clone())
+            .or_default()
+            .clone();
+        if progress.done {
+            self.output
+                .status(&format!("#{} already exported, skipping", name));
+            return Ok(());
+        }
+
+        let dir = self.out.join(name);
+        std::fs::create_dir_all(&dir)?;
+        let session = self.client.session();
+
+        // Page newest → oldest by moving `latest` down, so the checkpoint is a plain ts
+        // rather than a cursor that may have expired by the time we resume.
+        let mut latest = progress.cursor_ts.or_else(|| self.state.latest.clone());
+        loop {
+            let mut request = SlackApiConversationsHistoryRequest::new()
+                .with_channel(channel_id.clone())
+                .with_limit(200);
+            if let Some(oldest) = &self.state.oldest {
+                request = request.with_oldest(SlackTs::new(oldest.clone()));
+            }
+            if let Some(latest) = &latest {
+                request = request.with_latest(SlackTs::new(latest.clone()));
+            }
+            let response = session.conversations_history(&request).await?;
+            let Some(last) = response.messages.last() else {
+                break;
+            };
+            let page_oldest = last.origin.ts.0.clone();
+
+            let mut page = Vec::new();
+            for message in response.messages {
+                if message.parent.reply_count.is_some_and(|c| c > 0) {
+                    page.extend(self.fetch_replies(channel_id, &message.origin.ts).await?);
+                }
+                page.push(message);
+            }
+
+            if self.with_files {
+                self.download_files(&dir, &page).await?;
+            }
+            write_days(&dir, &page)?;
+
+            self.output
+                .status(&format!("#{}: exported up to {}", name, page_oldest));
+            latest = Some(page_oldest);
+            self.checkpoint(channel_id, latest.clone(), false)?;
+
+            if !response.has_more.unwrap_or(false) {
+                break;
+            }
+        }
+
+        self.checkpoint(channel_id, latest, true)
+    }
+
+    /// Fetch all replies in a thread, excluding the parent message.
+    async fn fetch_replies(
+        &self,
+        channel_id: &SlackChannelId,
+        thread_ts: &SlackTs,
+    ) -> Result<Vec<SlackHistoryMessage>> {
+        let session = self.client.session();
+        let request =
+            SlackApiConversationsRepliesRequest::new(channel_id.clone(), thread_ts.clone())
+                .with_limit(200);
+        let scroller = request.scroller();
+        let mut stream = scroller.to_items_stream(&session);
+
+        let mut replies = Vec::new();
+        while let Some(batch) = stream.try_next().await? {
+            replies.extend(batch.into_iter().filter(|m| m.origin.ts != *thread_ts));
+        }
+        Ok(replies)
+    }
+
+    /// Download files referenced by messages into `<channel>/files/`, skipping ones already on disk.
+    async fn download_files(&self, dir: &Path, messages: &[SlackHistoryMessage]) -> Result<()> {
+        let files_dir = dir.join("files");
+        for file in messages
+            .iter()
+            .flat_map(|m| m.content.files.iter().flatten())
+        {
+            let path = files_dir.join(file_name(&file.id.0, file.name.as_deref()));
+            if path.exists() {
+                continue;
+            }
+            match fetch_file(self.client, &file.id.0).await {
+                Ok((_, bytes)) => {
+                    std::fs::create_dir_all(&files_dir)?;
+                    std::fs::write(&path, bytes)?;
+                }
+                Err(e) => self
+                    .output
+                    .status(&format!("Skipping file {}: {}", file.id.0, e)),
+            }
+        }
+        Ok(())
+    }
+
+    fn checkpoint(
+        &mut self,
+        channel_id: &SlackChannelId,
+        cursor_ts: Option<String>,
+        done: bool,
+    ) -> Result<()> {
+        self.state
+            .channels
+            .insert(channel_id.0.clone(), ChannelProgress { cursor_ts, done });
+        write_json(&self.out.join(STATE_FILE), &self.state)
+    }
+}
+
+/// Resuming with the same expressions keeps the bounds they resolved to the first time,
+/// since relative ones resolve differently on every run. Anything else that moves the
+/// bounds starts over; day files are merged by ts, so that never duplicates messages.
+fn resume(
+    mut state: ExportState,
+    after: Option<&str>,
+    before: Option<&str>,
+    oldest: Option<String>,
+    latest: Option<String>,
+) -> ExportState {
+    // State files from before the expressions were recorded have bounds but no expressions
+    let recorded = state.after.is_some() == state.oldest.is_some()
+        && state.before.is_some() == state.latest.is_some();
+    let same_exprs =
+        recorded && state.after.as_deref() == after && state.before.as_deref() == before;
+    if !same_exprs && (state.oldest != oldest || state.latest != latest) {
+        state = ExportState {
+            oldest,
+            latest,
+            channels: HashMap::new(),
+            ..Default::default()
+        };
+    }
+    state.after = after.map(String::from);
+    state.before = before.map(String::from);
+    state
+}
+
+fn load_state(out: &Path) -> ExportState {
+    std::fs::read(out.join(STATE_FILE))
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+/// Merge messages into their per-day files, keyed (and so deduplicated) by ts.
+fn write_days(dir: &Path, messages: &[SlackHistoryMessage]) -> Result<()> {
+    let mut days: BTreeMap<String, Vec<serde_json::Value>> = BTreeMap::new();
+    for message in messages {
+        days.entry(day_of(&message.origin.ts.0)?)
+            .or_default()
+            .push(to_json(message)?);
+    }
+
+    for (day, new_messages) in days {
+        let path = dir.join(format!("{}.json", day));
+        let existing: Vec<serde_json::Value> = std::fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default();
+
+        let mut by_ts: BTreeMap<String, serde_json::Value> = BTreeMap::new();
+        for message in existing.into_iter().chain(new_messages) {
+            if let Some(ts) = message["ts"].as_str() {
+                by_ts.insert(ts.to_string(), message);
+            }
+        }
+        let merged: Vec<serde_json::Value> = by_ts.into_values().collect();
+        write_json(&path, &merged)?;
+    }
+    Ok(())
+}
+
+/// Count what has been written for a channel so far (includes previous runs).
+fn summarize(dir: &Path, channel: &str, name: &str) -> Result<ExportSummary> {
+    let mut summary = ExportSummary {
+        channel: channel.to_string(),
+        name: name.to_string(),
+        days: 0,
+        messages: 0,
+        replies: 0,
+        files: 0,
+    };
+
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "json") {
+            let messages: Vec<serde_json::Value> =
+                serde_json::from_slice(&std::fs::read(&path)?).map_err(anyhow::Error::from)?;
+            summary.days += 1;
+            for m in messages {
+                let is_reply = m["thread_ts"]
+                    .as_str()
+                    .is_some_and(|t| Some(t) != m["ts"].as_str());
+                if is_reply {
+                    summary.replies += 1;
+                } else {
+                    summary.messages += 1;
+                }
+            }
+        }
+    }
+
+    if let Ok(files) = std::fs::read_dir(dir.join("files")) {
+        summary.files = files.count();
+    }
+
+    Ok(summary)
+}
+
+/// `<id>-<name>` for a downloaded file, with anything that would leave `files/` replaced.
+fn file_name(id: &str, name: Option<&str>) -> String {
+    let name: String = name
+        .unwrap_or("file")
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    format!("{}-{}", id, name)
+}
+
+/// UTC date (`YYYY-MM-DD`) of a Slack timestamp.
+fn day_of(ts: &str) -> Result<String> {
+    Ok(parse_slack_ts(ts)
+        .map_err(SlackCliError::Api)?
+        .format("%Y-%m-%d")
+        .to_string())
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<serde_json::Value> {
+    Ok(serde_json::to_value(value).map_err(anyhow::Error::from)?)
+}
+
+/// Write pretty JSON via a temp file + rename so a crash never leaves a truncated file.
+fn write_json<T: Serialize + ?Sized>(path: &Path, value: &T) -> Result<()> {
+    let json = serde_json::to_vec_pretty(value).map_err(anyhow::Error::from)?;
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, json)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn progress(cursor_ts: &str) -> HashMap<String, ChannelProgress> {
+        HashMap::from([(
+            "C1".to_string(),
+            ChannelProgress {
+                cursor_ts: Some(cursor_ts.to_string()),
+                done: false,
+            },
+        )])
+    }
+
+    fn state(after: Option<&str>, oldest: Option<&str>) -> ExportState {
+        ExportState {
+            after: after.map(String::from),
+            before: None,
+            oldest: oldest.map(String::from),
+            latest: None,
+            channels: progress("500.000000"),
+        }
+    }
+
+    #[test]
+    fn test_resume_same_relative_expr_keeps_bounds() {
+        // `30d` resolves later on the second run, but the export continues where it was
+        let resumed = resume(
+            state(Some("30d"), Some("100.000000")),
+            Some("30d"),
+            None,
+            Some("200.000000".to_string()),
+            None,
+        );
+        assert_eq!(resumed.oldest.as_deref(), Some("100.000000"));
+        assert_eq!(
+            resumed.channels["C1"].cursor_ts.as_deref(),
+            Some("500.000000")
+        );
+    }
+
+    #[test]
+    fn test_resume_same_bounds_keeps_progress() {
+        let resumed = resume(
+            state(Some("2024-01-01"), Some("100.000000")),
+            Some("2024-01-01T00:00:00Z"),
+            None,
+            Some("100.000000".to_string()),
+            None,
+        );
+        assert_eq!(resumed.channels.len(), 1);
+        assert_eq!(resumed.after.as_deref(), Some("2024-01-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_resume_new_bounds_starts_over() {
+        let resumed = resume(
+            state(Some("30d"), Some("100.000000")),
+            Some("7d"),
+            None,
+            Some("300.000000".to_string()),
+            None,
+        );
+        assert_eq!(resumed.oldest.as_deref(), Some("300.000000"));
+        assert_eq!(resumed.after.as_deref(), Some("7d"));
+        assert!(resumed.channels.is_empty());
+    }
+
+    #[test]
+    fn test_resume_state_without_exprs() {
+        // State files written before `after`/`before` were recorded
+        let old: ExportState = serde_json::from_value(json!({
+            "oldest": "100.000000",
+            "latest": null,
+            "channels": {"C1": {"cursor_ts": "500.000000", "done": false}},
+        }))
+        .unwrap();
+        let resumed = resume(old, None, None, None, None);
+        assert!(resumed.channels.is_empty());
+        assert_eq!(resumed.oldest, None);
+    }
+
+    fn message(ts: &str, text: &str) -> SlackHistoryMessage {
+        serde_json::from_value(json!({"ts": ts, "user": "U1", "text": text})).unwrap()
+    }
+
+    fn read_day(dir: &Path, day: &str) -> Vec<(String, String)> {
+        let messages: Vec<serde_json::Value> =
+            serde_json::from_slice(&std::fs::read(dir.join(format!("{}.json", day))).unwrap())
+                .unwrap();
+        messages
+            .iter()
+            .map(|m| {
+                (
+                    m["ts"].as_str().unwrap().to_string(),
+                    m["text"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_write_days_merges_by_ts() {
+        let dir = std::env::temp_dir().join(format!("slackline-export-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // 2024-01-15 and 2024-01-16 (UTC)
+        write_days(
+            &dir,
+            &[
+                message("1705363200.000200", "second"),
+                message("1705363200.000100", "first"),
+            ],
+        )
+        .unwrap();
+        write_days(
+            &dir,
+            &[
+                message("1705363200.000200", "second, edited"),
+                message("1705363199.000000", "earlier"),
+                message("1705449600.000000", "next day"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            read_day(&dir, "2024-01-16"),
+            [
+                ("1705363200.000100".to_string(), "first".to_string()),
+                (
+                    "1705363200.000200".to_string(),
+                    "second, edited".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            read_day(&dir, "2024-01-15"),
+            [("1705363199.000000".to_string(), "earlier".to_string())]
+        );
+        assert_eq!(read_day(&dir, "2024-01-17").len(), 1);
+        assert_eq!(summarize(&dir, "C1", "general").unwrap().messages, 4);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name("F1", Some("report.pdf")), "F1-report.pdf");
+        assert_eq!(file_name("F1", Some("a/b\\c.txt")), "F1-a_b_c.txt");
+        assert_eq!(file_name("F1", Some("../../etc")), "F1-.._.._etc");
+        assert_eq!(file_name("F1", None), "F1-file");
+    }
+}
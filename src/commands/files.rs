@@ -135,6 +135,24 @@ pub async fn list(
 
 /// Download a file to stdout or a path
 pub async fn download(client: &Client, file_id: &str, output_path: Option<&str>) -> Result<()> {
+    let (filename, bytes) = fetch_file(client, file_id).await?;
+
+    match output_path {
+        Some(path) => {
+            std::fs::write(path, &bytes)?;
+            eprintln!("Downloaded {} to {}", filename, path);
+        }
+        None => {
+            use std::io::Write;
+            std::io::stdout().write_all(&bytes)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Fetch a file's name and contents
+pub async fn fetch_file(client: &Client, file_id: &str) -> Result<(String, Vec<u8>)> {
     let session = client.session();
     let request = SlackApiFilesInfoRequest::new(SlackFileId(file_id.to_string()));
 
@@ -163,18 +181,7 @@ pub async fn download(client: &Client, file_id: &str, output_path: Option<&str>)
 
     let filename = file.name.unwrap_or_else(|| "file".to_string());
 
-    match output_path {
-        Some(path) => {
-            std::fs::write(path, &bytes)?;
-            eprintln!("Downloaded {} to {}", filename, path);
-        }
-        None => {
-            use std::io::Write;
-            std::io::stdout().write_all(&bytes)?;
-        }
-    }
-
-    Ok(())
+    Ok((filename, bytes.to_vec()))
 }
 
 /// Upload a file to Slack (3-step upload flow)
@@ -186,8 +193,6 @@ pub async fn upload(
     thread_ts: Option<&str>,
     comment: Option<&str>,
 ) -> Result<()> {
-    let session = client.session();
-
     // Read the file
     let file_bytes = std::fs::read(path)
         .map_err(|e| SlackCliError::Api(format!("Failed to read {}: {}", path, e)))?;
@@ -196,13 +201,47 @@ pub async fn upload(
         .and_then(|n| n.to_str())
         .unwrap_or("file")
         .to_string();
-    let file_size = file_bytes.len() as u64;
 
     // Detect content type
     let content_type = mime_guess::from_path(path)
         .first_or_octet_stream()
         .to_string();
 
+    let channel_id = match channel {
+        Some(ch) => Some(client.resolve_channel(ch).await?),
+        None => None,
+    };
+
+    let uploaded = upload_bytes(
+        client,
+        filename,
+        file_bytes,
+        content_type,
+        channel_id,
+        thread_ts,
+        comment,
+    )
+    .await?;
+
+    output.print(&uploaded);
+    output.success("File uploaded");
+
+    Ok(())
+}
+
+/// Upload in-memory content, optionally sharing it to a channel or thread
+pub async fn upload_bytes(
+    client: &Client,
+    filename: String,
+    file_bytes: Vec<u8>,
+    content_type: String,
+    channel_id: Option<SlackChannelId>,
+    thread_ts: Option<&str>,
+    comment: Option<&str>,
+) -> Result<UploadedFile> {
+    let session = client.session();
+    let file_size = file_bytes.len() as u64;
+
     // Step 1: Get upload URL
     let url_request =
         SlackApiFilesGetUploadUrlExternalRequest::new(filename.clone(), file_size as usize);
@@ -216,8 +255,7 @@ pub async fn upload(
     // Step 3: Complete the upload
     let file_complete = SlackApiFilesComplete::new(url_response.file_id.clone());
     let mut complete_request = SlackApiFilesCompleteUploadExternalRequest::new(vec![file_complete]);
-    if let Some(ch) = channel {
-        let channel_id = client.resolve_channel(ch).await?;
+    if let Some(channel_id) = channel_id {
         complete_request = complete_request.with_channel_id(channel_id);
     }
     if let Some(ts) = thread_ts {
@@ -230,14 +268,9 @@ pub async fn upload(
         .files_complete_upload_external(&complete_request)
         .await?;
 
-    let uploaded = UploadedFile {
+    Ok(UploadedFile {
         id: url_response.file_id.0,
         name: filename,
         size: file_size,
-    };
-
-    output.print(&uploaded);
-    output.success("File uploaded");
-
-    Ok(())
+    })
 }
@@ -0,0 +1,202 @@
+use crate::client::Client;
+use crate::commands::channels::{
+    HistoryOptions, MessageInfo, enrich_messages, fetch_history, message_from_slack,
+};
+use crate::error::Result;
+use crate::output::{HumanReadable, Output};
+use chrono::Utc;
+use colored::Colorize;
+use futures::TryStreamExt;
+use serde::Serialize;
+use slack_morphism::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Recent top-level messages re-checked each poll for new thread replies.
+const THREAD_WINDOW: u16 = 100;
+
+struct Follower<'a> {
+    client: &'a Client,
+    output: &'a Output,
+    channel_id: SlackChannelId,
+    opts: &'a HistoryOptions,
+    /// Newest top-level message printed so far
+    last_seen: String,
+    /// Latest reply printed per thread parent
+    threads: HashMap<String, String>,
+    /// Replies older than this (in threads we haven't printed) are never shown
+    started: String,
+}
+
+/// Print recent history, then poll for new messages (and, with `--with-replies`,
+/// new thread replies) every `interval` until ctrl-c. Prints JSONL with `--json`.
+pub async fn follow(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    opts: &HistoryOptions,
+    interval: Duration,
+) -> Result<()> {
+    let channel_id = client.resolve_channel(channel).await?;
+    let started = format!("{}.000000", Utc::now().timestamp());
+
+    let mut messages = fetch_history(client, &channel_id, opts).await?;
+    messages.reverse();
+
+    let mut follower = Follower {
+        client,
+        output,
+        channel_id,
+        opts,
+        last_seen: messages
+            .last()
+            .map(|m| m.ts.clone())
+            .unwrap_or_else(|| started.clone()),
+        threads: HashMap::new(),
+        started,
+    };
+    for m in &messages {
+        follower.track(m);
+        output.print(m);
+    }
+
+    output.status(&format!(
+        "following {} every {}s... (ctrl-c to stop)",
+        channel,
+        interval.as_secs()
+    ));
+
+    let mut stop = std::pin::pin!(tokio::signal::ctrl_c());
+    loop {
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = &mut stop => break,
+        }
+        // A failed poll (network blip, rate limit) shouldn't end the tail
+        if let Err(e) = follower.poll().await {
+            output.error(&format!("poll failed: {}", e));
+        }
+    }
+
+    output.status("stopped");
+
+    Ok(())
+}
+
+impl Follower<'_> {
+    /// Remember the newest reply already shown for a message's thread.
+    fn track(&mut self, m: &MessageInfo) {
+        let latest = m
+            .replies
+            .iter()
+            .flatten()
+            .map(|r| r.ts.clone())
+            .chain(std::iter::once(m.ts.clone()))
+            .max();
+        if let Some(latest) = latest {
+            self.threads.insert(m.ts.clone(), latest);
+        }
+    }
+
+    async fn poll(&mut self) -> Result<()> {
+        self.poll_messages().await?;
+        if self.opts.with_replies {
+            self.poll_replies().await?;
+        }
+        Ok(())
+    }
+
+    async fn poll_messages(&mut self) -> Result<()> {
+        let session = self.client.session();
+        let request = SlackApiConversationsHistoryRequest::new()
+            .with_channel(self.channel_id.clone())
+            .with_oldest(SlackTs::new(self.last_seen.clone()))
+            .with_limit(200);
+        let scroller = request.scroller();
+        let mut stream = scroller.to_items_stream(&session);
+        let mut messages: Vec<MessageInfo> = Vec::new();
+        while let Some(batch) = stream.try_next().await? {
+            messages.extend(batch.into_iter().map(message_from_slack));
+        }
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        messages.sort_by(|a, b| a.ts.cmp(&b.ts));
+        if self.opts.enrich {
+            enrich_messages(self.client, &mut messages).await;
+        }
+        for m in &messages {
+            self.track(m);
+            self.output.print(m);
+        }
+        if let Some(last) = messages.last() {
+            self.last_seen = last.ts.clone();
+        }
+        Ok(())
+    }
+
+    /// Replies don't show up in `conversations.history`, so check the latest reply
+    /// of recent threads and fetch whatever is newer than what we've printed.
+    async fn poll_replies(&mut self) -> Result<()> {
+        let session = self.client.session();
+        let request = SlackApiConversationsHistoryRequest::new()
+            .with_channel(self.channel_id.clone())
+            .with_limit(THREAD_WINDOW);
+        let recent = session.conversations_history(&request).await?.messages;
+
+        for parent in recent {
+            let Some(latest_reply) = parent.parent.latest_reply else {
+                continue;
+            };
+            let seen = self
+                .threads
+                .get(&parent.origin.ts.0)
+                .cloned()
+                .unwrap_or_else(|| self.started.clone());
+            if latest_reply.0 <= seen {
+                continue;
+            }
+
+            let request =
+                SlackApiConversationsRepliesRequest::new(self.channel_id.clone(), parent.origin.ts)
+                    .with_oldest(SlackTs::new(seen))
+                    .with_limit(200);
+            let thread_ts = request.ts.clone();
+            let scroller = request.scroller();
+            let mut stream = scroller.to_items_stream(&session);
+            let mut replies: Vec<MessageInfo> = Vec::new();
+            while let Some(batch) = stream.try_next().await? {
+                replies.extend(
+                    batch
+                        .into_iter()
+                        .filter(|m| m.origin.ts != thread_ts)
+                        .map(message_from_slack),
+                );
+            }
+
+            replies.sort_by(|a, b| a.ts.cmp(&b.ts));
+            if self.opts.enrich {
+                enrich_messages(self.client, &mut replies).await;
+            }
+            for reply in &replies {
+                self.output.print(&ThreadReply(reply));
+            }
+            self.threads.insert(thread_ts.0, latest_reply.0);
+        }
+        Ok(())
+    }
+}
+
+/// A reply printed under a `↳ thread` marker in human mode, as a plain message in JSON.
+#[derive(Serialize)]
+#[serde(transparent)]
+struct ThreadReply<'a>(&'a MessageInfo);
+
+impl HumanReadable for ThreadReply<'_> {
+    fn print_human(&self) {
+        let thread_ts = self.0.thread_ts.as_deref().unwrap_or_default();
+        println!("{}", format!("↳ thread {}", thread_ts).dimmed());
+        self.0.print_indented("    ");
+    }
+}
@@ -1,10 +1,17 @@
 use crate::client::Client;
+use crate::commands::channels::{MarkedRead, latest_ts, mark_read};
 use crate::error::Result;
 use crate::output::{HumanReadable, Output};
+use crate::timeparse::parse_slack_ts;
 use colored::Colorize;
 use futures::future::join_all;
+use futures::{StreamExt, TryStreamExt};
 use serde::Serialize;
 use slack_morphism::prelude::*;
+use std::collections::HashSet;
+
+/// Conversations checked and marked at once by `mark-all-read`.
+const MARK_CONCURRENCY: usize = 8;
 
 #[derive(Debug, Serialize)]
 pub struct MyChannel {
@@ -195,3 +202,107 @@ pub async fn clear_status(client: &Client, output: &Output) -> Result<()> {
 
     Ok(())
 }
+
+/// Mark every conversation the current user is in as read, except `except`
+pub async fn mark_all_read(client: &Client, output: &Output, except: &[String]) -> Result<()> {
+    let session = client.session();
+    let skip: HashSet<SlackChannelId> =
+        client.resolve_channels(except).await?.into_iter().collect();
+
+    output.status("Listing conversations...");
+    let request = SlackApiUsersConversationsRequest::new()
+        .with_types(vec![
+            SlackConversationType::Public,
+            SlackConversationType::Private,
+            SlackConversationType::Mpim,
+            SlackConversationType::Im,
+        ])
+        .with_exclude_archived(true)
+        .with_limit(200);
+    let scroller = request.scroller();
+    let mut stream = scroller.to_items_stream(&session);
+    let mut conversations = Vec::new();
+    while let Some(batch) = stream.try_next().await? {
+        conversations.extend(batch.into_iter().filter(|c| !skip.contains(&c.id)));
+    }
+
+    output.status(&format!(
+        "Checking {} conversations...",
+        conversations.len()
+    ));
+    let results: Vec<Option<MarkedRead>> = futures::stream::iter(conversations)
+        .map(|c| async move {
+            let name = c.name.unwrap_or_else(|| c.id.0.clone());
+            match mark_if_unread(client, &c.id).await {
+                Ok(None) => None,
+                Ok(Some(ts)) => Some(MarkedRead {
+                    channel: c.id.0,
+                    name,
+                    ts: Some(ts.0),
+                    error: None,
+                }),
+                Err(e) => Some(MarkedRead {
+                    channel: c.id.0,
+                    name,
+                    ts: None,
+                    error: Some(e.to_string()),
+                }),
+            }
+        })
+        .buffer_unordered(MARK_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut results: Vec<MarkedRead> = results.into_iter().flatten().collect();
+    results.sort_by(|a, b| a.name.cmp(&b.name));
+    let failed = results.iter().filter(|r| r.error.is_some()).count();
+    let marked = results.len() - failed;
+
+    output.print_list_wrapped(
+        &results,
+        "Marked as read",
+        &serde_json::json!({
+            "marked": marked,
+            "failed": failed,
+            "skipped": skip.len(),
+            "channels": results,
+        }),
+    );
+    output.success(&format!(
+        "Marked {} conversations as read ({} failed, {} skipped)",
+        marked,
+        failed,
+        skip.len()
+    ));
+
+    Ok(())
+}
+
+/// Mark a conversation read up to its latest message if it has unreads.
+/// Returns the ts it was marked at, or `None` if it was already read.
+async fn mark_if_unread(client: &Client, channel_id: &SlackChannelId) -> Result<Option<SlackTs>> {
+    let session = client.session();
+    let request = SlackApiConversationsInfoRequest::new(channel_id.clone());
+    let last_read = session
+        .conversations_info(&request)
+        .await?
+        .channel
+        .last_state
+        .last_read;
+
+    let Some(latest) = latest_ts(client, channel_id).await? else {
+        return Ok(None);
+    };
+    let already_read = last_read.is_some_and(|read| {
+        matches!(
+            (parse_slack_ts(&latest.0), parse_slack_ts(&read.0)),
+            (Ok(latest), Ok(read)) if latest <= read
+        )
+    });
+    if already_read {
+        return Ok(None);
+    }
+
+    mark_read(client, channel_id, &latest).await?;
+    Ok(Some(latest))
+}
@@ -1,10 +1,21 @@
-use crate::client::Client;
-use crate::error::Result;
+use crate::blocks::{fallback_text, load_blocks};
+use crate::client::{Client, retry_rate_limited};
+use crate::commands::channels::{
+    FullMessage, MessageInfo, is_member, message_from_slack, parse_full_messages,
+};
+use crate::commands::files::{UploadedFile, upload_bytes};
+use crate::error::{Result, SlackCliError};
+use crate::mrkdwn::{markdown_to_mrkdwn, split_text};
 use crate::output::{HumanReadable, Output};
+use crate::timeparse::{parse_slack_ts, parse_time_expr};
 use chrono::{DateTime, Utc};
 use colored::Colorize;
+use futures::TryStreamExt;
+use regex::Regex;
 use serde::Serialize;
 use slack_morphism::prelude::*;
+use std::collections::HashSet;
+use std::time::Duration;
 
 #[derive(Debug, Serialize)]
 pub struct ReplyInfo {
@@ -61,12 +72,20 @@ pub struct SentMessage {
     pub channel: String,
     pub ts: String,
     pub text: String,
+    /// Timestamps of the thread replies carrying the rest of a split body
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parts: Vec<String>,
+    #[serde(flatten)]
+    pub options: PostOptions,
 }
 
 impl HumanReadable for SentMessage {
     fn print_human(&self) {
         println!("{} in {}", "Message sent".green(), self.channel);
         println!("  ts: {}", self.ts.dimmed());
+        if !self.parts.is_empty() {
+            println!("  continued in {} thread replies", self.parts.len());
+        }
     }
 }
 
@@ -76,9 +95,27 @@ pub async fn replies(
     channel: &str,
     thread_ts: &str,
     limit: Option<u16>,
+    full: bool,
 ) -> Result<()> {
     let session = client.session();
     let channel_id = client.resolve_channel(channel).await?;
+
+    if full {
+        let limit = limit.unwrap_or(100).to_string();
+        let response = client
+            .api_get(
+                "conversations.replies",
+                &[
+                    ("channel", &channel_id.0),
+                    ("ts", thread_ts),
+                    ("limit", &limit),
+                ],
+            )
+            .await?;
+        let replies = parse_full_messages(&response)?;
+        output.print_list(&replies, &format!("Thread replies in {}", channel));
+        return Ok(());
+    }
     let ts = SlackTs::new(thread_ts.to_string());
 
     let request =
@@ -90,8 +127,7 @@ pub async fn replies(
         .messages
         .into_iter()
         .map(|m| {
-            let ts_float: f64 = m.origin.ts.0.parse().unwrap_or(0.0);
-            let timestamp = DateTime::from_timestamp(ts_float as i64, 0);
+            let timestamp = parse_slack_ts(&m.origin.ts.0).ok();
 
             ReplyInfo {
                 ts: m.origin.ts.0,
@@ -107,6 +143,60 @@ pub async fn replies(
     Ok(())
 }
 
+/// Fetch one message with all its fields, whether it's in the channel or a thread reply
+pub async fn get(client: &Client, output: &Output, channel: &str, ts: &str) -> Result<()> {
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let message = match fetch_message(client, &channel_id, ts).await? {
+        Some(message) => message,
+        None => {
+            return Err(SlackCliError::Api(format!(
+                "Message {} not found in {}",
+                ts, channel
+            )));
+        }
+    };
+
+    output.print(&message);
+
+    Ok(())
+}
+
+/// Look a message up by ts: channel history first, then threads (replies aren't in history).
+pub async fn fetch_message(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    ts: &str,
+) -> Result<Option<FullMessage>> {
+    let bounds = [
+        ("channel", channel_id.0.as_str()),
+        ("oldest", ts),
+        ("latest", ts),
+        ("inclusive", "true"),
+    ];
+
+    let mut params = bounds.to_vec();
+    params.push(("limit", "1"));
+    let response = client.api_get("conversations.history", &params).await?;
+    let found = parse_full_messages(&response)?
+        .into_iter()
+        .find(|m| m.ts == ts);
+    if found.is_some() {
+        return Ok(found);
+    }
+
+    // Given a reply's ts, conversations.replies returns the parent plus that reply
+    let mut params = bounds.to_vec();
+    params.extend([("ts", ts), ("limit", "2")]);
+    match client.api_get("conversations.replies", &params).await {
+        Ok(response) => Ok(parse_full_messages(&response)?
+            .into_iter()
+            .find(|m| m.ts == ts)),
+        Err(SlackCliError::Api(e)) if e == "thread_not_found" => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
 pub async fn permalink(
     client: &Client,
     output: &Output,
@@ -131,19 +221,133 @@ pub async fn permalink(
     Ok(())
 }
 
+/// Longest quote `forward --quote` copies before cutting it off.
+const FORWARD_QUOTE_CHARS: usize = 1000;
+
+#[derive(Debug, Serialize)]
+pub struct ForwardedMessage {
+    pub channel: String,
+    pub ts: String,
+    pub from_channel: String,
+    pub from_ts: String,
+    pub permalink: String,
+}
+
+impl HumanReadable for ForwardedMessage {
+    fn print_human(&self) {
+        println!("{} to {}", "Message forwarded".green(), self.channel);
+        println!("  ts: {}", self.ts.dimmed());
+        println!("  original: {}", self.permalink.dimmed());
+    }
+}
+
+/// Share a message in another channel or a DM by posting its permalink, which Slack
+/// unfurls as a preview. `to` is a channel, or a user (`@name` or ID) to DM.
+pub async fn forward(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    ts: &str,
+    to: &str,
+    comment: Option<&str>,
+    quote: bool,
+) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let request =
+        SlackApiChatGetPermalinkRequest::new(channel_id.clone(), SlackTs::new(ts.to_string()));
+    let permalink = session
+        .chat_get_permalink(&request)
+        .await?
+        .permalink
+        .to_string();
+
+    let mut lines: Vec<String> = comment.map(String::from).into_iter().collect();
+    if quote {
+        let message = fetch_message(client, &channel_id, ts)
+            .await?
+            .ok_or_else(|| {
+                SlackCliError::Api(format!("Message {} not found in {}", ts, channel))
+            })?;
+        let mut text: String = message.text.chars().take(FORWARD_QUOTE_CHARS).collect();
+        if text.len() < message.text.len() {
+            text.push('…');
+        }
+        lines.extend(text.lines().map(|line| format!("> {}", line)));
+
+        // The author's name rather than a mention, so forwarding doesn't ping them
+        if let Some(user) = message.user {
+            let name = client
+                .user_names(std::slice::from_ref(&user))
+                .await
+                .remove(&user)
+                .unwrap_or(user);
+            lines.push(format!("> — *{}*", name));
+        }
+    }
+    lines.push(permalink.clone());
+
+    let dest_id = if to.starts_with('@') || Client::looks_like_user_id(to) {
+        let user_id = client.resolve_user(to).await?;
+        let open_request = SlackApiConversationsOpenRequest::new().with_users(vec![user_id]);
+        session.conversations_open(&open_request).await?.channel.id
+    } else {
+        client.resolve_channel(to).await?
+    };
+
+    let body = MessageBody {
+        text: lines.join("\n"),
+        blocks: None,
+    };
+    let opts = PostOptions {
+        unfurl_links: Some(true),
+        ..Default::default()
+    };
+    let posted_ts = post_messages(client, &dest_id, &body, None, &opts)
+        .await?
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+
+    let forwarded = ForwardedMessage {
+        channel: dest_id.0,
+        ts: posted_ts,
+        from_channel: channel_id.0,
+        from_ts: ts.to_string(),
+        permalink,
+    };
+    output.print(&forwarded);
+
+    Ok(())
+}
+
 /// Get reactions on a message
 pub async fn reactions(client: &Client, output: &Output, channel: &str, ts: &str) -> Result<()> {
-    let session = client.session();
     let channel_id = client.resolve_channel(channel).await?;
-    let timestamp = SlackTs::new(ts.to_string());
+    let reactions = fetch_reactions(client, &channel_id, ts).await?;
 
-    let request = SlackApiReactionsGetRequest::new()
-        .with_channel(channel_id)
-        .with_timestamp(timestamp);
+    output.print_list(
+        &reactions,
+        &format!("Reactions on message {} in {}", ts, channel),
+    );
 
-    let response = session.reactions_get(&request).await?;
+    Ok(())
+}
 
-    let reactions = match response {
+/// Reactions on a message, with every user who reacted (not just the first few).
+pub async fn fetch_reactions(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    ts: &str,
+) -> Result<Vec<ReactionInfo>> {
+    let session = client.session();
+    let request = SlackApiReactionsGetRequest::new()
+        .with_channel(channel_id.clone())
+        .with_timestamp(SlackTs::new(ts.to_string()))
+        .with_full(true);
+
+    let reactions = match session.reactions_get(&request).await? {
         SlackApiReactionsGetResponse::Message(msg) => msg
             .message
             .content
@@ -155,45 +359,763 @@ pub async fn reactions(client: &Client, output: &Output, channel: &str, ts: &str
                 count: r.count as u64,
                 users: r.users.into_iter().map(|u| u.0).collect(),
             })
-            .collect::<Vec<_>>(),
+            .collect(),
         SlackApiReactionsGetResponse::File(_) => vec![],
     };
 
-    output.print_list(
-        &reactions,
-        &format!("Reactions on message {} in {}", ts, channel),
-    );
+    Ok(reactions)
+}
 
-    Ok(())
+/// Slack truncates long message text; longer bodies are split or uploaded as a snippet.
+pub const MAX_MESSAGE_CHARS: usize = 4000;
+
+/// Text and optional Block Kit blocks of an outgoing message.
+pub struct MessageBody {
+    pub text: String,
+    pub blocks: Option<Vec<SlackBlock>>,
 }
 
-/// Send a message to a channel
+impl MessageBody {
+    /// Build a body from message text (`-` for stdin) or a text `file`, and/or a `--blocks`
+    /// file (`-` for stdin). `markdown` converts the text from CommonMark to mrkdwn. With
+    /// blocks, `text` is the notification fallback and defaults to the first header or
+    /// section text.
+    pub fn new(
+        text: Option<String>,
+        file: Option<&str>,
+        markdown: bool,
+        blocks: Option<&str>,
+    ) -> Result<Self> {
+        if text.as_deref() == Some("-") && blocks == Some("-") {
+            return Err(SlackCliError::Config(
+                "Message text and --blocks can't both be read from stdin".to_string(),
+            ));
+        }
+        let blocks = blocks
+            .map(load_blocks)
+            .transpose()
+            .map_err(SlackCliError::Config)?;
+        let text = match (text, file) {
+            (Some(text), _) if text == "-" => Some(std::io::read_to_string(std::io::stdin())?),
+            (_, Some(path)) => Some(std::fs::read_to_string(path).map_err(|e| {
+                SlackCliError::Config(format!("Failed to read message from {}: {}", path, e))
+            })?),
+            (text, None) => text,
+        };
+        let text = text.map(|text| {
+            if markdown {
+                markdown_to_mrkdwn(&text)
+            } else {
+                text.trim_end().to_string()
+            }
+        });
+        if blocks.is_none() && text.as_deref().is_some_and(|t| t.trim().is_empty()) {
+            return Err(SlackCliError::Config("Message text is empty".to_string()));
+        }
+        let text = match (text, &blocks) {
+            (Some(text), _) => text,
+            (None, Some(blocks)) => fallback_text(blocks).unwrap_or_default(),
+            (None, None) => {
+                return Err(SlackCliError::Config(
+                    "Message text or --blocks is required".to_string(),
+                ));
+            }
+        };
+        Ok(Self { text, blocks })
+    }
+
+    pub fn content(&self) -> SlackMessageContent {
+        let content = SlackMessageContent::new().with_text(self.text.clone());
+        match &self.blocks {
+            Some(blocks) => content.with_blocks(blocks.clone()),
+            None => content,
+        }
+    }
+
+    /// Text too long for a single message. Blocks are left to Slack's own limits.
+    pub fn is_long(&self) -> bool {
+        self.blocks.is_none() && self.text.chars().count() > MAX_MESSAGE_CHARS
+    }
+}
+
+/// `chat.postMessage` switches for `messages send` and `dms send`. Only the ones that were
+/// set are sent, and echoed back in the output JSON.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct PostOptions {
+    /// Also post a thread reply to the channel
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub reply_broadcast: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
+    /// Turn plain `@name` and `#channel` into mentions and links
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub link_names: bool,
+    /// `false` sends the text literally, without mrkdwn formatting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrkdwn: Option<bool>,
+    /// Upload text over the message limit as a snippet instead of splitting it
+    #[serde(skip)]
+    pub snippet: bool,
+}
+
+impl PostOptions {
+    /// Add the switches to a serialized `chat.postMessage`/`chat.scheduleMessage` request.
+    /// slack-morphism has no `mrkdwn` field, so requests go out as JSON.
+    fn apply(&self, request: &mut serde_json::Value, first: bool) {
+        if self.reply_broadcast && first {
+            request["reply_broadcast"] = true.into();
+        }
+        if let Some(unfurl) = self.unfurl_links {
+            request["unfurl_links"] = unfurl.into();
+        }
+        if let Some(unfurl) = self.unfurl_media {
+            request["unfurl_media"] = unfurl.into();
+        }
+        if self.link_names {
+            request["link_names"] = true.into();
+        }
+        if let Some(mrkdwn) = self.mrkdwn {
+            request["mrkdwn"] = mrkdwn.into();
+        }
+    }
+}
+
+/// Serialize a slack-morphism request for the raw `Client::api_post`.
+pub fn to_json<T: Serialize>(request: &T) -> Result<serde_json::Value> {
+    serde_json::to_value(request)
+        .map_err(|e| SlackCliError::Api(format!("Failed to encode message: {}", e)))
+}
+
+/// Where a body ended up: one or more messages, or a snippet.
+pub enum Posted {
+    /// Timestamps of the first message and of any continuation replies
+    Messages(Vec<String>),
+    Snippet(UploadedFile),
+}
+
+/// Post a body to a channel (or thread). Text over the message limit is split into
+/// several messages in the same thread, or uploaded as a text snippet with `opts.snippet`.
+pub async fn post_body(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    body: &MessageBody,
+    thread_ts: Option<&str>,
+    opts: &PostOptions,
+) -> Result<Posted> {
+    if body.is_long() && opts.snippet {
+        let uploaded = upload_bytes(
+            client,
+            "message.txt".to_string(),
+            body.text.clone().into_bytes(),
+            "text/plain".to_string(),
+            Some(channel_id.clone()),
+            thread_ts,
+            None,
+        )
+        .await?;
+        return Ok(Posted::Snippet(uploaded));
+    }
+
+    Ok(Posted::Messages(
+        post_messages(client, channel_id, body, thread_ts, opts).await?,
+    ))
+}
+
+/// Post a body as messages, splitting text over the limit into replies in the same thread.
+/// Returns the timestamps of the first message and of any continuation replies.
+pub async fn post_messages(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    body: &MessageBody,
+    thread_ts: Option<&str>,
+    opts: &PostOptions,
+) -> Result<Vec<String>> {
+    let chunks = if body.is_long() {
+        split_text(&body.text, MAX_MESSAGE_CHARS)
+    } else {
+        vec![body.text.clone()]
+    };
+
+    let mut posted: Vec<String> = Vec::new();
+    for (i, chunk) in chunks.into_iter().enumerate() {
+        let content = if i == 0 && !body.is_long() {
+            body.content()
+        } else {
+            SlackMessageContent::new().with_text(chunk)
+        };
+        let mut request = SlackApiChatPostMessageRequest::new(channel_id.clone(), content);
+        if let Some(ts) = thread_ts.or(posted.first().map(String::as_str)) {
+            request = request.with_thread_ts(SlackTs::new(ts.to_string()));
+        }
+        let mut request = to_json(&request)?;
+        opts.apply(&mut request, i == 0);
+        let response = client.api_post("chat.postMessage", &request).await?;
+        posted.push(response["ts"].as_str().unwrap_or_default().to_string());
+    }
+
+    Ok(posted)
+}
+
+#[derive(Debug, Serialize)]
+pub struct EphemeralMessage {
+    pub channel: String,
+    pub user: String,
+    pub message_ts: Option<String>,
+    pub text: String,
+}
+
+impl HumanReadable for EphemeralMessage {
+    fn print_human(&self) {
+        println!(
+            "{} to {} in {}",
+            "Ephemeral message sent".green(),
+            self.user,
+            self.channel
+        );
+        if let Some(ts) = &self.message_ts {
+            println!("  ts: {}", ts.dimmed());
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScheduledMessage {
+    pub id: String,
+    pub channel: String,
+    pub post_at: Option<DateTime<Utc>>,
+    pub text: String,
+}
+
+impl HumanReadable for ScheduledMessage {
+    fn print_human(&self) {
+        let when = self
+            .post_at
+            .map(|t| {
+                t.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_default();
+        println!(
+            "{} {} {}",
+            when.cyan(),
+            self.channel.green(),
+            self.id.dimmed()
+        );
+        println!("  {}", self.text);
+        println!();
+    }
+}
+
+/// Send a message to a channel, or schedule it with `at`. Long bodies are split into
+/// the same thread, or uploaded as a snippet with `opts.snippet`.
 pub async fn send(
     client: &Client,
     output: &Output,
     channel: &str,
-    text: &str,
+    body: &MessageBody,
     thread_ts: Option<&str>,
+    at: Option<&str>,
+    opts: &PostOptions,
 ) -> Result<()> {
-    let session = client.session();
     let channel_id = client.resolve_channel(channel).await?;
-    let content = SlackMessageContent::new().with_text(text.to_string());
 
-    let mut request = SlackApiChatPostMessageRequest::new(channel_id, content);
+    if let Some(at) = at {
+        if body.is_long() {
+            return Err(SlackCliError::Config(format!(
+                "Scheduled messages can't be split: text is longer than {} characters",
+                MAX_MESSAGE_CHARS
+            )));
+        }
+        let content = body.content();
+        let post_at = parse_post_at(at)?;
+        let mut request = SlackApiChatScheduleMessageRequest::new(
+            channel_id.clone(),
+            content,
+            SlackDateTime(post_at),
+        );
+        if let Some(ts) = thread_ts {
+            request = request.with_thread_ts(SlackTs::new(ts.to_string()));
+        }
+
+        let mut request = to_json(&request)?;
+        opts.apply(&mut request, true);
+        let response = client.api_post("chat.scheduleMessage", &request).await?;
+
+        let scheduled = ScheduledMessage {
+            id: response["scheduled_message_id"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            channel: channel_id.0,
+            post_at: Some(post_at),
+            text: body.text.clone(),
+        };
+
+        output.print(&scheduled);
+        output.success(&format!(
+            "Message scheduled for {}",
+            post_at
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+        ));
+
+        return Ok(());
+    }
+
+    match post_body(client, &channel_id, body, thread_ts, opts).await? {
+        Posted::Snippet(uploaded) => {
+            output.print(&uploaded);
+            output.success("Message uploaded as a snippet");
+        }
+        Posted::Messages(mut ts) => {
+            let parts = ts.split_off(1);
+            let sent = SentMessage {
+                channel: channel_id.0,
+                ts: ts.remove(0),
+                text: body.text.clone(),
+                parts,
+                options: opts.clone(),
+            };
+
+            output.print(&sent);
+            output.success("Message sent");
+        }
+    }
+
+    Ok(())
+}
+
+/// Show a message only to one member of a channel (`chat.postEphemeral`)
+pub async fn send_ephemeral(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    user: &str,
+    body: &MessageBody,
+    thread_ts: Option<&str>,
+) -> Result<()> {
+    if body.is_long() {
+        return Err(SlackCliError::Config(format!(
+            "Ephemeral messages can't be split: text is longer than {} characters",
+            MAX_MESSAGE_CHARS
+        )));
+    }
+    let channel_id = client.resolve_channel(channel).await?;
+    let user_id = client.resolve_user(user).await?;
+
+    // Slack answers `user_not_in_channel` too, but only after the fact and without naming who
+    if !is_member(client, &channel_id, &user_id).await? {
+        return Err(SlackCliError::Api(format!(
+            "{} is not a member of {}",
+            user, channel
+        )));
+    }
+
+    let mut request =
+        SlackApiChatPostEphemeralRequest::new(channel_id.clone(), user_id.clone(), body.content());
     if let Some(ts) = thread_ts {
         request = request.with_thread_ts(SlackTs::new(ts.to_string()));
     }
+    // slack-morphism drops `message_ts` from the response, so post it directly
+    let response = client
+        .api_post("chat.postEphemeral", &to_json(&request)?)
+        .await?;
+
+    let sent = EphemeralMessage {
+        channel: channel_id.0,
+        user: user_id.0,
+        message_ts: response["message_ts"].as_str().map(String::from),
+        text: body.text.clone(),
+    };
+
+    output.print(&sent);
+    output.success("Ephemeral message sent");
+
+    Ok(())
+}
+
+/// Slack only accepts scheduled messages up to 120 days ahead.
+const MAX_SCHEDULE_DAYS: i64 = 120;
+
+fn parse_post_at(at: &str) -> Result<DateTime<Utc>> {
+    let ts = parse_time_expr(at).map_err(SlackCliError::Api)?;
+    let post_at = parse_slack_ts(&ts).map_err(SlackCliError::Api)?;
+
+    let now = Utc::now();
+    if post_at <= now {
+        return Err(SlackCliError::Api(format!(
+            "Scheduled time '{}' is in the past",
+            at
+        )));
+    }
+    if post_at > now + chrono::TimeDelta::days(MAX_SCHEDULE_DAYS) {
+        return Err(SlackCliError::Api(format!(
+            "Scheduled time '{}' is more than {} days ahead",
+            at, MAX_SCHEDULE_DAYS
+        )));
+    }
+    Ok(post_at)
+}
+
+/// List messages scheduled by the current user (`chat.scheduledMessages.list`)
+pub async fn scheduled_list(client: &Client, output: &Output, channel: Option<&str>) -> Result<()> {
+    let channel_id = match channel {
+        Some(channel) => Some(client.resolve_channel(channel).await?),
+        None => None,
+    };
+
+    let messages = fetch_scheduled(client, channel_id.as_ref()).await?;
+
+    output.print_list(&messages, "Scheduled messages");
+
+    Ok(())
+}
+
+/// Page through `chat.scheduledMessages.list` (raw API: slack-morphism omits the text).
+async fn fetch_scheduled(
+    client: &Client,
+    channel_id: Option<&SlackChannelId>,
+) -> Result<Vec<ScheduledMessage>> {
+    let mut messages = Vec::new();
+    let mut cursor = String::new();
+    loop {
+        let mut params: Vec<(&str, &str)> = vec![("limit", "100")];
+        if let Some(channel_id) = channel_id {
+            params.push(("channel", &channel_id.0));
+        }
+        if !cursor.is_empty() {
+            params.push(("cursor", &cursor));
+        }
+        let response = client
+            .api_get("chat.scheduledMessages.list", &params)
+            .await?;
+
+        for m in response["scheduled_messages"]
+            .as_array()
+            .into_iter()
+            .flatten()
+        {
+            messages.push(ScheduledMessage {
+                id: m["id"].as_str().unwrap_or_default().to_string(),
+                channel: m["channel_id"].as_str().unwrap_or_default().to_string(),
+                post_at: m["post_at"]
+                    .as_i64()
+                    .and_then(|t| DateTime::from_timestamp(t, 0)),
+                text: m["text"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+
+        match response["response_metadata"]["next_cursor"].as_str() {
+            Some(next) if !next.is_empty() => cursor = next.to_string(),
+            _ => break,
+        }
+    }
+    messages.sort_by_key(|m| m.post_at);
+    Ok(messages)
+}
 
-    let response = session.chat_post_message(&request).await?;
+/// Cancel a scheduled message. The channel is looked up when not given.
+pub async fn scheduled_delete(
+    client: &Client,
+    output: &Output,
+    id: &str,
+    channel: Option<&str>,
+) -> Result<()> {
+    let session = client.session();
+    let channel_id = match channel {
+        Some(channel) => client.resolve_channel(channel).await?,
+        None => fetch_scheduled(client, None)
+            .await?
+            .into_iter()
+            .find(|m| m.id == id)
+            .map(|m| SlackChannelId::new(m.channel))
+            .ok_or_else(|| SlackCliError::Api(format!("Scheduled message not found: {}", id)))?,
+    };
+
+    let request = SlackApiChatDeleteScheduledMessageRequest::new(
+        channel_id,
+        SlackScheduledMid::new(id.to_string()),
+    );
+    session.chat_delete_scheduled_message(&request).await?;
 
-    let sent = SentMessage {
+    output.success(&format!("Scheduled message {} deleted", id));
+
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+pub struct EditedMessage {
+    pub channel: String,
+    pub ts: String,
+    pub text: String,
+    /// When the edit was made
+    pub edited_ts: Option<String>,
+}
+
+impl HumanReadable for EditedMessage {
+    fn print_human(&self) {
+        println!("{} in {}", "Message edited".green(), self.channel);
+        println!("  ts: {}", self.ts.dimmed());
+        println!("  {}", self.text);
+    }
+}
+
+/// Replace the text of a message (`chat.update`)
+pub async fn edit(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    ts: &str,
+    body: &MessageBody,
+) -> Result<()> {
+    if body.is_long() {
+        return Err(SlackCliError::Config(format!(
+            "Message text is longer than {} characters",
+            MAX_MESSAGE_CHARS
+        )));
+    }
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+    let content = body.content();
+
+    let request = SlackApiChatUpdateRequest::new(channel_id, content, SlackTs::new(ts.to_string()));
+    let response = session.chat_update(&request).await?;
+
+    let edited = EditedMessage {
         channel: response.channel.0,
         ts: response.ts.0,
-        text: text.to_string(),
+        text: response
+            .message
+            .content
+            .text
+            .unwrap_or_else(|| body.text.clone()),
+        edited_ts: response.message.edited.map(|e| e.ts.0),
     };
 
-    output.print(&sent);
-    output.success("Message sent");
+    output.print(&edited);
+    output.success("Message edited");
+
+    Ok(())
+}
+
+/// Delete a message (`chat.delete`), asking first with `confirm`
+pub async fn delete(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    ts: &str,
+    confirm: bool,
+) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+
+    if confirm && !output.confirm(&format!("Delete message {} in {}?", ts, channel))? {
+        output.status("Aborted");
+        return Ok(());
+    }
+
+    let request = SlackApiChatDeleteRequest::new(channel_id, SlackTs::new(ts.to_string()));
+    session.chat_delete(&request).await?;
+
+    output.success("Message deleted");
+
+    Ok(())
+}
+
+/// Filters and safety switches for `messages purge`.
+#[derive(Debug, Default)]
+pub struct PurgeOptions {
+    /// Only your own messages
+    pub from_me: bool,
+    /// Only messages older than this time expression
+    pub before: Option<String>,
+    /// Only messages whose text matches this regex
+    pub pattern: Option<String>,
+    /// Skip the confirmation prompt
+    pub yes: bool,
+    /// Report what would be deleted without deleting
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PurgeResult {
+    pub channel: String,
+    pub matched: usize,
+    pub deleted: usize,
+    pub failed: usize,
+    pub dry_run: bool,
+    /// Newest matching messages
+    pub sample: Vec<MessageInfo>,
+}
+
+impl HumanReadable for PurgeResult {
+    fn print_human(&self) {
+        if self.dry_run {
+            println!(
+                "{} {} messages in {}",
+                "Would delete".yellow(),
+                self.matched,
+                self.channel
+            );
+            return;
+        }
+        let failed = if self.failed > 0 {
+            format!(" ({} failed)", self.failed).red().to_string()
+        } else {
+            String::new()
+        };
+        println!(
+            "{} {} of {} messages in {}{}",
+            "Deleted".green(),
+            self.deleted,
+            self.matched,
+            self.channel,
+            failed
+        );
+    }
+}
+
+/// Messages shown before asking for confirmation.
+const PURGE_SAMPLE: usize = 5;
+/// `chat.delete` is Tier 3 (~50 calls per minute).
+const PURGE_DELETE_INTERVAL: Duration = Duration::from_millis(1200);
+/// Bulk-delete channel history and thread replies matching the filters, after showing a
+/// count and sample
+pub async fn purge(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    opts: &PurgeOptions,
+) -> Result<()> {
+    // Without a filter every message in the channel is a candidate, and an admin token
+    // can delete them all
+    if !opts.from_me && opts.before.is_none() && opts.pattern.is_none() {
+        return Err(SlackCliError::Config(
+            "purge needs at least one of --from-me, --before or --match".to_string(),
+        ));
+    }
+    let channel_id = client.resolve_channel(channel).await?;
+    let pattern = opts
+        .pattern
+        .as_deref()
+        .map(Regex::new)
+        .transpose()
+        .map_err(|e| SlackCliError::Config(format!("Invalid --match regex: {}", e)))?;
+    let me = if opts.from_me {
+        Some(client.auth_test().await?.user_id)
+    } else {
+        None
+    };
+
+    output.status(&format!("Scanning {}...", channel));
+    let session = client.session();
+    let latest = opts
+        .before
+        .as_deref()
+        .map(parse_time_expr)
+        .transpose()
+        .map_err(SlackCliError::Api)?
+        .map(SlackTs::new);
+    let mut request = SlackApiConversationsHistoryRequest::new()
+        .with_channel(channel_id.clone())
+        .with_limit(200);
+    if let Some(latest) = &latest {
+        request = request.with_latest(latest.clone());
+    }
+    let scroller = request.scroller();
+    let mut stream = scroller.to_items_stream(&session);
+    let mut history: Vec<SlackHistoryMessage> = Vec::new();
+    while let Some(batch) = stream.try_next().await? {
+        history.extend(batch);
+    }
+
+    // Replies only show up in history when broadcast, so page each thread too
+    let seen: HashSet<SlackTs> = history.iter().map(|m| m.origin.ts.clone()).collect();
+    let mut replies: Vec<SlackHistoryMessage> = Vec::new();
+    for parent in history
+        .iter()
+        .filter(|m| m.parent.reply_count.is_some_and(|c| c > 0))
+    {
+        let mut request =
+            SlackApiConversationsRepliesRequest::new(channel_id.clone(), parent.origin.ts.clone())
+                .with_limit(200);
+        if let Some(latest) = &latest {
+            request = request.with_latest(latest.clone());
+        }
+        let scroller = request.scroller();
+        let mut stream = scroller.to_items_stream(&session);
+        while let Some(batch) = stream.try_next().await? {
+            replies.extend(batch.into_iter().filter(|m| !seen.contains(&m.origin.ts)));
+        }
+    }
+
+    let mut candidates: Vec<MessageInfo> = history
+        .into_iter()
+        .chain(replies)
+        .filter(|m| me.is_none() || m.sender.user == me)
+        .map(message_from_slack)
+        .filter(|m| pattern.as_ref().is_none_or(|re| re.is_match(&m.text)))
+        .collect();
+    candidates.sort_by_key(|m| std::cmp::Reverse(parse_slack_ts(&m.ts).ok()));
+
+    let mut result = PurgeResult {
+        channel: channel_id.0.clone(),
+        matched: candidates.len(),
+        deleted: 0,
+        failed: 0,
+        dry_run: opts.dry_run,
+        sample: candidates.iter().take(PURGE_SAMPLE).cloned().collect(),
+    };
+
+    if candidates.is_empty() {
+        output.print(&result);
+        return Ok(());
+    }
+    if !output.is_json() {
+        output.print_list(
+            &result.sample,
+            &format!("{} matching messages, newest first", candidates.len()),
+        );
+    }
+    if opts.dry_run {
+        output.print(&result);
+        return Ok(());
+    }
+    if !opts.yes
+        && !output.confirm(&format!(
+            "Delete {} messages in {}?",
+            candidates.len(),
+            channel
+        ))?
+    {
+        output.status("Aborted");
+        return Ok(());
+    }
+
+    for (i, m) in candidates.iter().enumerate() {
+        if i > 0 {
+            tokio::time::sleep(PURGE_DELETE_INTERVAL).await;
+        }
+        let body = serde_json::json!({ "channel": channel_id.0, "ts": m.ts });
+        match retry_rate_limited(|| client.api_post("chat.delete", &body)).await {
+            Ok(_) => result.deleted += 1,
+            Err(e) => {
+                result.failed += 1;
+                output.error(&format!("Failed to delete {}: {}", m.ts, e));
+            }
+        }
+        if (i + 1) % 10 == 0 || i + 1 == candidates.len() {
+            output.status(&format!("deleted {}/{}", result.deleted, candidates.len()));
+        }
+    }
+
+    output.print(&result);
+    if result.failed == 0 {
+        output.success(&format!("Purged {} messages", result.deleted));
+    }
 
     Ok(())
 }
@@ -1,9 +1,20 @@
+pub mod approval;
+pub mod bookmarks;
 pub mod channels;
+pub mod db;
 pub mod dms;
+pub mod export;
 pub mod files;
+pub mod follow;
 pub mod me;
 pub mod messages;
+pub mod polls;
+pub mod reminders;
 pub mod search;
+pub mod stats;
+pub mod stream;
+pub mod sync;
 pub mod token;
+pub mod upsert;
 pub mod users;
 pub mod watch;
@@ -0,0 +1,408 @@
+use crate::client::Client;
+use crate::commands::messages::{fetch_message, fetch_reactions};
+use crate::error::{Result, SlackCliError};
+use crate::output::{HumanReadable, Output};
+use colored::Colorize;
+use serde::Serialize;
+use slack_morphism::prelude::*;
+use std::collections::HashMap;
+
+/// First line of every poll; `results` uses it to recognise the options below.
+const POLL_MARKER: &str = ":bar_chart:";
+
+/// Emojis for options given without one, in order.
+const DEFAULT_EMOJIS: &[&str] = &[
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "keycap_ten",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PollOption {
+    pub emoji: String,
+    pub label: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Poll {
+    pub channel: String,
+    pub ts: String,
+    pub question: String,
+    pub options: Vec<PollOption>,
+}
+
+impl HumanReadable for Poll {
+    fn print_human(&self) {
+        println!("{} {}", "Poll posted:".green(), self.question.bold());
+        for option in &self.options {
+            println!("  :{}: {}", option.emoji, option.label);
+        }
+        println!("  ts: {}", self.ts.dimmed());
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Voter {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl Voter {
+    fn display(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.id)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct OptionTally {
+    pub emoji: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub count: usize,
+    pub voters: Vec<Voter>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PollResults {
+    pub channel: String,
+    pub ts: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub question: Option<String>,
+    pub voters: usize,
+    pub options: Vec<OptionTally>,
+    /// With one vote per user: voters who picked several options, whose votes were dropped
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub spoiled: Vec<Voter>,
+}
+
+impl HumanReadable for PollResults {
+    fn print_human(&self) {
+        if let Some(question) = &self.question {
+            println!("{}", question.bold());
+        }
+        let total: usize = self.options.iter().map(|o| o.count).sum();
+        for option in &self.options {
+            let percent = (option.count * 100).checked_div(total).unwrap_or(0);
+            let bar = "█".repeat(percent / 5);
+            println!(
+                "  :{}: {}  {} {} ({}%)",
+                option.emoji,
+                option.label.as_deref().unwrap_or_default(),
+                bar.cyan(),
+                option.count,
+                percent
+            );
+            if !option.voters.is_empty() {
+                let names: Vec<&str> = option.voters.iter().map(Voter::display).collect();
+                println!("      {}", names.join(", ").dimmed());
+            }
+        }
+        println!("  {} voters", self.voters);
+        if !self.spoiled.is_empty() {
+            let names: Vec<&str> = self.spoiled.iter().map(Voter::display).collect();
+            println!(
+                "  {} {}",
+                "Dropped (voted more than once):".yellow(),
+                names.join(", ")
+            );
+        }
+    }
+}
+
+/// `"Pizza=:pizza:"` → (Pizza, pizza); a bare `"Pizza"` takes the next numbered emoji.
+fn parse_options(options: &[String]) -> Result<Vec<PollOption>> {
+    if options.len() < 2 {
+        return Err(SlackCliError::Config(
+            "A poll needs at least two --option values".to_string(),
+        ));
+    }
+
+    let mut parsed: Vec<PollOption> = Vec::new();
+    for (i, option) in options.iter().enumerate() {
+        let explicit = option.rsplit_once('=').and_then(|(label, emoji)| {
+            let emoji = emoji.trim().trim_matches(':');
+            (!emoji.is_empty() && !emoji.contains(char::is_whitespace))
+                .then(|| (label.trim(), emoji.to_string()))
+        });
+        let (label, emoji) = match explicit {
+            Some(pair) => pair,
+            None => {
+                let emoji = DEFAULT_EMOJIS.get(i).ok_or_else(|| {
+                    SlackCliError::Config(format!(
+                        "Option '{}' needs an emoji (e.g. '{}=:tada:'): only the first {} get numbered ones",
+                        option,
+                        option,
+                        DEFAULT_EMOJIS.len()
+                    ))
+                })?;
+                (option.trim(), emoji.to_string())
+            }
+        };
+        if label.is_empty() {
+            return Err(SlackCliError::Config(format!(
+                "Option '{}' has no label",
+                option
+            )));
+        }
+        if parsed.iter().any(|p| p.emoji == emoji) {
+            return Err(SlackCliError::Config(format!(
+                "Emoji :{}: is used by more than one option",
+                emoji
+            )));
+        }
+        parsed.push(PollOption {
+            emoji,
+            label: label.to_string(),
+        });
+    }
+    Ok(parsed)
+}
+
+fn poll_text(question: &str, options: &[PollOption]) -> String {
+    let mut text = format!("{} *{}*\n", POLL_MARKER, question);
+    for option in options {
+        text.push_str(&format!(":{}: {}\n", option.emoji, option.label));
+    }
+    text.push_str("_React to vote_");
+    text
+}
+
+/// Read the question and options back from a poll's text (None if it isn't one of ours).
+fn parse_poll_text(text: &str) -> Option<(String, Vec<PollOption>)> {
+    let unescape = |s: &str| {
+        s.replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&amp;", "&")
+    };
+    let mut lines = text.lines();
+    let question = lines
+        .next()?
+        .strip_prefix(POLL_MARKER)?
+        .trim()
+        .trim_matches('*')
+        .to_string();
+    let options = lines
+        .filter_map(|line| {
+            let (emoji, label) = line.strip_prefix(':')?.split_once(": ")?;
+            Some(PollOption {
+                emoji: emoji.to_string(),
+                label: unescape(label.trim()),
+            })
+        })
+        .collect();
+    Some((unescape(&question), options))
+}
+
+/// Post a poll and add each option's reaction so voters only have to click
+pub async fn create(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    question: &str,
+    options: &[String],
+) -> Result<()> {
+    let options = parse_options(options)?;
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let request = SlackApiChatPostMessageRequest::new(
+        channel_id.clone(),
+        SlackMessageContent::new().with_text(poll_text(question, &options)),
+    );
+    let ts = session.chat_post_message(&request).await?.ts;
+
+    for option in &options {
+        let request = SlackApiReactionsAddRequest::new(
+            channel_id.clone(),
+            SlackReactionName::new(option.emoji.clone()),
+            ts.clone(),
+        );
+        session.reactions_add(&request).await.map_err(|e| {
+            SlackCliError::Api(format!(
+                "Poll posted ({}), but adding :{}: failed: {}",
+                ts, option.emoji, e
+            ))
+        })?;
+    }
+
+    let poll = Poll {
+        channel: channel_id.0,
+        ts: ts.0,
+        question: question.to_string(),
+        options,
+    };
+    output.print(&poll);
+
+    Ok(())
+}
+
+/// Tally a poll's reactions. The poster's own reactions are the seeded options and don't count.
+pub async fn results(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    ts: &str,
+    one_vote: bool,
+) -> Result<()> {
+    let channel_id = client.resolve_channel(channel).await?;
+    let message = fetch_message(client, &channel_id, ts)
+        .await?
+        .ok_or_else(|| SlackCliError::Api(format!("Message {} not found in {}", ts, channel)))?;
+    let poll = parse_poll_text(&message.text);
+    let reactions = fetch_reactions(client, &channel_id, ts).await?;
+
+    // Options in poll order; reactions that aren't options are ignored. For messages
+    // that aren't slackline polls, every reaction is an option. Skin tones count as one.
+    let base = |name: &str| name.split("::").next().unwrap_or(name).to_string();
+    let mut tallies: Vec<(String, Option<String>, Vec<String>)> = Vec::new();
+    match &poll {
+        Some((_, options)) => {
+            for option in options {
+                tallies.push((option.emoji.clone(), Some(option.label.clone()), Vec::new()));
+            }
+        }
+        None => {
+            for reaction in &reactions {
+                let emoji = base(&reaction.name);
+                if !tallies.iter().any(|(e, _, _)| *e == emoji) {
+                    tallies.push((emoji, None, Vec::new()));
+                }
+            }
+        }
+    }
+    for reaction in reactions {
+        let emoji = base(&reaction.name);
+        let Some((_, _, voters)) = tallies.iter_mut().find(|(e, _, _)| *e == emoji) else {
+            continue;
+        };
+        for user in reaction.users {
+            if message.user.as_deref() != Some(&user) && !voters.contains(&user) {
+                voters.push(user);
+            }
+        }
+    }
+
+    let mut votes: HashMap<String, usize> = HashMap::new();
+    for (_, _, voters) in &tallies {
+        for voter in voters {
+            *votes.entry(voter.clone()).or_default() += 1;
+        }
+    }
+    let mut spoiled: Vec<String> = Vec::new();
+    if one_vote {
+        spoiled = votes
+            .iter()
+            .filter(|&(_, &n)| n > 1)
+            .map(|(id, _)| id.clone())
+            .collect();
+        spoiled.sort();
+        for (_, _, voters) in tallies.iter_mut() {
+            voters.retain(|v| !spoiled.contains(v));
+        }
+    }
+
+    let ids: Vec<String> = votes.keys().cloned().collect();
+    let names = client.user_names(&ids).await;
+    let voter = |id: String| Voter {
+        name: names.get(&id).cloned(),
+        id,
+    };
+
+    let results = PollResults {
+        channel: channel_id.0,
+        ts: ts.to_string(),
+        question: poll.map(|(question, _)| question),
+        voters: votes.len() - spoiled.len(),
+        options: tallies
+            .into_iter()
+            .map(|(emoji, label, voters)| OptionTally {
+                emoji,
+                label,
+                count: voters.len(),
+                voters: voters.into_iter().map(voter).collect(),
+            })
+            .collect(),
+        spoiled: spoiled.into_iter().map(voter).collect(),
+    };
+    output.print(&results);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opts(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let parsed = parse_options(&opts(&["Pizza=:pizza:", "Sushi = sushi", "Tacos"])).unwrap();
+        let pairs: Vec<(&str, &str)> = parsed
+            .iter()
+            .map(|o| (o.label.as_str(), o.emoji.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [("Pizza", "pizza"), ("Sushi", "sushi"), ("Tacos", "three")]
+        );
+
+        // An '=' without an emoji after it is part of the label
+        let parsed = parse_options(&opts(&["x = y z", "B"])).unwrap();
+        assert_eq!(parsed[0].label, "x = y z");
+        assert_eq!(parsed[0].emoji, "one");
+    }
+
+    #[test]
+    fn test_parse_options_errors() {
+        assert!(parse_options(&opts(&["Only"])).is_err());
+        assert!(parse_options(&opts(&["A=:one:", "B=one"])).is_err());
+        assert!(parse_options(&opts(&["=:one:", "B"])).is_err());
+        let eleven: Vec<String> = (0..11).map(|i| format!("Option {}", i)).collect();
+        assert!(parse_options(&eleven).is_err());
+    }
+
+    #[test]
+    fn test_poll_text_round_trip() {
+        let options =
+            parse_options(&opts(&["Pizza=:pizza:", "Fish & chips", "+1 <maybe>"])).unwrap();
+        let text = poll_text("Lunch?", &options);
+        assert!(text.starts_with(":bar_chart: *Lunch?*\n:pizza: Pizza\n"));
+
+        // Slack stores &, < and > escaped
+        let stored = text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        let (question, parsed) = parse_poll_text(&stored).unwrap();
+        assert_eq!(question, "Lunch?");
+        let pairs: Vec<(&str, &str)> = parsed
+            .iter()
+            .map(|o| (o.emoji.as_str(), o.label.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("pizza", "Pizza"),
+                ("two", "Fish & chips"),
+                ("three", "+1 <maybe>")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_not_a_poll() {
+        assert!(parse_poll_text("Lunch?\n:pizza: Pizza").is_none());
+        assert!(parse_poll_text("").is_none());
+    }
+}
@@ -0,0 +1,161 @@
+use crate::client::Client;
+use crate::error::{Result, SlackCliError};
+use crate::output::{HumanReadable, Output};
+use crate::timeparse::{parse_slack_ts, parse_time_expr};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub struct Reminder {
+    pub id: String,
+    pub text: String,
+    pub user: String,
+    pub creator: Option<String>,
+    pub recurring: bool,
+    /// When it fires (absent for recurring reminders)
+    pub time: Option<DateTime<Utc>>,
+    pub completed: Option<DateTime<Utc>>,
+}
+
+impl HumanReadable for Reminder {
+    fn print_human(&self) {
+        let when = match (self.time, self.recurring) {
+            (Some(t), _) => t
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            (None, true) => "recurring".to_string(),
+            (None, false) => String::new(),
+        };
+        let done = if self.completed.is_some() {
+            " ✓".green().to_string()
+        } else {
+            String::new()
+        };
+        println!("{} {}{}", when.cyan(), self.id.dimmed(), done);
+        println!("  {}", self.text);
+        println!();
+    }
+}
+
+/// A reminder as `reminders.*` returns it (unix seconds, 0 for "not completed").
+#[derive(Deserialize)]
+struct RawReminder {
+    id: String,
+    text: String,
+    user: String,
+    creator: Option<String>,
+    #[serde(default)]
+    recurring: bool,
+    time: Option<i64>,
+    complete_ts: Option<i64>,
+}
+
+impl From<RawReminder> for Reminder {
+    fn from(r: RawReminder) -> Self {
+        let at = |secs: Option<i64>| {
+            secs.filter(|&s| s > 0)
+                .and_then(|s| DateTime::from_timestamp(s, 0))
+        };
+        Reminder {
+            id: r.id,
+            text: r.text,
+            user: r.user,
+            creator: r.creator,
+            recurring: r.recurring,
+            time: at(r.time),
+            completed: at(r.complete_ts),
+        }
+    }
+}
+
+fn parse_reminder(value: &serde_json::Value) -> Result<Reminder> {
+    serde_json::from_value::<RawReminder>(value.clone())
+        .map(Reminder::from)
+        .map_err(|e| SlackCliError::Api(format!("Unexpected reminders response: {}", e)))
+}
+
+/// Turn `--at` into what `reminders.add` takes: a unix timestamp for one-off times, or
+/// Slack's own phrasing for recurring ones (`every weekday at 9am`), which it parses itself.
+fn reminder_time(at: &str) -> Result<String> {
+    if at.trim().to_ascii_lowercase().starts_with("every ") {
+        return Ok(at.trim().to_string());
+    }
+    let ts = parse_time_expr(at).map_err(SlackCliError::Api)?;
+    let time = parse_slack_ts(&ts).map_err(SlackCliError::Api)?;
+    if time <= Utc::now() {
+        return Err(SlackCliError::Api(format!(
+            "Reminder time '{}' is in the past",
+            at
+        )));
+    }
+    Ok(time.timestamp().to_string())
+}
+
+/// Add a reminder for yourself, or for another user
+pub async fn add(
+    client: &Client,
+    output: &Output,
+    text: &str,
+    at: &str,
+    user: Option<&str>,
+) -> Result<()> {
+    let mut body = serde_json::json!({
+        "text": text,
+        "time": reminder_time(at)?,
+    });
+    if let Some(user) = user {
+        body["user"] = client.resolve_user(user).await?.0.into();
+    }
+
+    let response = client.api_post("reminders.add", &body).await?;
+    let reminder = parse_reminder(&response["reminder"])?;
+
+    output.print(&reminder);
+    output.success("Reminder added");
+
+    Ok(())
+}
+
+/// List your reminders, soonest first. Completed ones only with `all`.
+pub async fn list(client: &Client, output: &Output, all: bool) -> Result<()> {
+    let response = client.api_get("reminders.list", &[]).await?;
+    let mut reminders = response["reminders"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(parse_reminder)
+        .collect::<Result<Vec<_>>>()?;
+
+    if !all {
+        reminders.retain(|r| r.completed.is_none());
+    }
+    reminders.sort_by_key(|r| (r.time.is_none(), r.time));
+
+    output.print_list(&reminders, "Reminders");
+
+    Ok(())
+}
+
+/// Mark a reminder as complete
+pub async fn complete(client: &Client, output: &Output, id: &str) -> Result<()> {
+    client
+        .api_post("reminders.complete", &serde_json::json!({ "reminder": id }))
+        .await?;
+
+    output.success(&format!("Completed reminder {}", id));
+
+    Ok(())
+}
+
+/// Delete a reminder
+pub async fn delete(client: &Client, output: &Output, id: &str) -> Result<()> {
+    client
+        .api_post("reminders.delete", &serde_json::json!({ "reminder": id }))
+        .await?;
+
+    output.success(&format!("Deleted reminder {}", id));
+
+    Ok(())
+}
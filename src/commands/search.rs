@@ -1,6 +1,7 @@
 use crate::client::Client;
 use crate::error::{Result, SlackCliError};
 use crate::output::{HumanReadable, Output};
+use crate::timeparse::parse_slack_ts;
 use chrono::{DateTime, Utc};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
@@ -129,8 +130,7 @@ pub async fn messages(
         .matches
         .into_iter()
         .map(|m| {
-            let ts_float: f64 = m.ts.parse().unwrap_or(0.0);
-            let timestamp = DateTime::from_timestamp(ts_float as i64, 0);
+            let timestamp = parse_slack_ts(&m.ts).ok();
 
             SearchResult {
                 ts: m.ts,
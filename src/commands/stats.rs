@@ -0,0 +1,438 @@
+use crate::client::Client;
+use crate::error::{Result, SlackCliError};
+use crate::output::{HumanReadable, Output};
+use crate::timeparse::{parse_slack_ts, parse_time_expr};
+use chrono::{Datelike, Local, Timelike};
+use colored::Colorize;
+use futures::TryStreamExt;
+use serde::Serialize;
+use slack_morphism::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+#[derive(Debug, Serialize)]
+pub struct UserCount {
+    pub user: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayCount {
+    pub date: String,
+    pub messages: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReactionCount {
+    pub name: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChannelStats {
+    pub channel: String,
+    pub after: Option<String>,
+    pub before: Option<String>,
+    /// Top-level messages
+    pub messages: u64,
+    /// Thread replies
+    pub replies: u64,
+    pub threads: u64,
+    /// Median time from a thread's parent to its first reply
+    pub median_reply_latency_secs: Option<i64>,
+    pub bot_messages: u64,
+    pub human_messages: u64,
+    pub bot_share: f64,
+    pub per_user: Vec<UserCount>,
+    pub per_day: Vec<DayCount>,
+    /// Messages by local weekday (Mon first) and hour
+    pub hour_of_week: [[u64; 24]; 7],
+    pub top_reactions: Vec<ReactionCount>,
+    pub top_reactors: Vec<UserCount>,
+}
+
+impl HumanReadable for ChannelStats {
+    fn print_human(&self) {
+        println!("{}", format!("Activity in {}", self.channel).bold());
+        println!("{}", "─".repeat(40));
+        println!(
+            "{} messages, {} replies in {} threads",
+            self.messages, self.replies, self.threads
+        );
+        if let Some(secs) = self.median_reply_latency_secs {
+            println!("Median time to first reply: {}", format_duration(secs));
+        }
+        println!(
+            "Humans: {}  Bots: {} ({:.0}% bot)",
+            self.human_messages,
+            self.bot_messages,
+            self.bot_share * 100.0
+        );
+
+        println!("\n{}", "Top posters".bold());
+        for u in &self.per_user {
+            println!(
+                "  {:>6}  {}",
+                u.count,
+                u.name.as_deref().unwrap_or(&u.user).green()
+            );
+        }
+
+        println!("\n{}", "Per day".bold());
+        let max_day = self.per_day.iter().map(|d| d.messages).max().unwrap_or(0);
+        for d in &self.per_day {
+            let bar = "█".repeat(scale(d.messages, max_day, 40));
+            println!("  {} {:>5} {}", d.date.dimmed(), d.messages, bar.cyan());
+        }
+
+        println!("\n{}", "Hour of week (local time)".bold());
+        println!("       {}", "0     6     12    18   ".dimmed());
+        let max_hour = self
+            .hour_of_week
+            .iter()
+            .flatten()
+            .copied()
+            .max()
+            .unwrap_or(0);
+        for (day, hours) in WEEKDAYS.iter().zip(&self.hour_of_week) {
+            let row: String = hours
+                .iter()
+                .map(|&n| [' ', '░', '▒', '▓', '█'][scale(n, max_hour, 4)])
+                .collect();
+            println!("  {}  {}", day.dimmed(), row);
+        }
+
+        if !self.top_reactions.is_empty() {
+            println!("\n{}", "Top reactions".bold());
+            for r in &self.top_reactions {
+                println!("  {:>6}  :{}:", r.count, r.name);
+            }
+            println!("\n{}", "Top reactors".bold());
+            for u in &self.top_reactors {
+                println!(
+                    "  {:>6}  {}",
+                    u.count,
+                    u.name.as_deref().unwrap_or(&u.user).green()
+                );
+            }
+        }
+    }
+}
+
+/// Scale `n` into `0..=steps` relative to `max`, rounding non-zero counts up to at least 1.
+fn scale(n: u64, max: u64, steps: usize) -> usize {
+    if n == 0 || max == 0 {
+        return 0;
+    }
+    ((n as f64 / max as f64 * steps as f64).ceil() as usize).clamp(1, steps)
+}
+
+fn format_duration(secs: i64) -> String {
+    match secs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h{:02}m", s / 3600, (s % 3600) / 60),
+        s => format!("{}d{}h", s / 86400, (s % 86400) / 3600),
+    }
+}
+
+fn is_bot(m: &SlackHistoryMessage) -> bool {
+    m.sender.bot_id.is_some()
+        || m.subtype.as_ref().is_some_and(|s| {
+            serde_json::to_value(s)
+                .ok()
+                .is_some_and(|v| v.as_str() == Some("bot_message"))
+        })
+}
+
+/// Page through channel history (with thread replies) and report activity statistics.
+pub async fn channel_stats(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    after: Option<&str>,
+    before: Option<&str>,
+    top: usize,
+) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+
+    let mut request = SlackApiConversationsHistoryRequest::new()
+        .with_channel(channel_id.clone())
+        .with_limit(200);
+    if let Some(after) = after {
+        let ts = parse_time_expr(after).map_err(SlackCliError::Api)?;
+        request = request.with_oldest(SlackTs::new(ts));
+    }
+    if let Some(before) = before {
+        let ts = parse_time_expr(before).map_err(SlackCliError::Api)?;
+        request = request.with_latest(SlackTs::new(ts));
+    }
+
+    output.status("Fetching history...");
+    let mut messages: Vec<SlackHistoryMessage> = Vec::new();
+    let scroller = request.scroller();
+    let mut stream = scroller.to_items_stream(&session);
+    while let Some(batch) = stream.try_next().await? {
+        messages.extend(batch);
+    }
+
+    let parents: Vec<SlackTs> = messages
+        .iter()
+        .filter(|m| m.parent.reply_count.is_some_and(|c| c > 0))
+        .map(|m| m.origin.ts.clone())
+        .collect();
+
+    output.status(&format!("Fetching {} threads...", parents.len()));
+    let mut threads: Vec<(SlackTs, Vec<SlackHistoryMessage>)> = Vec::new();
+    for parent_ts in parents {
+        let request =
+            SlackApiConversationsRepliesRequest::new(channel_id.clone(), parent_ts.clone())
+                .with_limit(200);
+        let scroller = request.scroller();
+        let mut stream = scroller.to_items_stream(&session);
+        let mut thread: Vec<SlackHistoryMessage> = Vec::new();
+        while let Some(batch) = stream.try_next().await? {
+            thread.extend(batch.into_iter().filter(|m| m.origin.ts != parent_ts));
+        }
+        threads.push((parent_ts, thread));
+    }
+
+    let mut stats = aggregate(channel, after, before, &messages, &threads, top);
+    let ids: Vec<String> = stats
+        .per_user
+        .iter()
+        .chain(&stats.top_reactors)
+        .map(|u| u.user.clone())
+        .collect();
+    let names = client.user_names(&ids).await;
+    for u in stats
+        .per_user
+        .iter_mut()
+        .chain(stats.top_reactors.iter_mut())
+    {
+        u.name = names.get(&u.user).cloned();
+    }
+
+    output.print(&stats);
+
+    Ok(())
+}
+
+/// Activity statistics over top-level `messages` and the replies of each thread
+/// (`(parent ts, replies without the parent)`). User names are left for the caller.
+fn aggregate(
+    channel: &str,
+    after: Option<&str>,
+    before: Option<&str>,
+    messages: &[SlackHistoryMessage],
+    threads: &[(SlackTs, Vec<SlackHistoryMessage>)],
+    top: usize,
+) -> ChannelStats {
+    let seen: HashSet<&SlackTs> = messages.iter().map(|m| &m.origin.ts).collect();
+    let mut replies: Vec<&SlackHistoryMessage> = Vec::new();
+    let mut latencies: Vec<i64> = Vec::new();
+    for (parent_ts, thread) in threads {
+        let first = thread
+            .iter()
+            .filter_map(|m| parse_slack_ts(&m.origin.ts.0).ok())
+            .min();
+        if let (Some(first), Ok(parent)) = (first, parse_slack_ts(&parent_ts.0)) {
+            latencies.push((first - parent).num_seconds());
+        }
+        // Broadcast replies also appear in history; count them once
+        replies.extend(thread.iter().filter(|m| !seen.contains(&m.origin.ts)));
+    }
+
+    let mut per_user: HashMap<String, u64> = HashMap::new();
+    let mut per_day: BTreeMap<String, u64> = BTreeMap::new();
+    let mut hour_of_week = [[0u64; 24]; 7];
+    let mut reactions: HashMap<String, u64> = HashMap::new();
+    let mut reactors: HashMap<String, u64> = HashMap::new();
+    let mut bot_messages = 0;
+
+    for m in messages.iter().chain(replies.iter().copied()) {
+        if is_bot(m) {
+            bot_messages += 1;
+        }
+        if let Some(user) = &m.sender.user {
+            *per_user.entry(user.0.clone()).or_default() += 1;
+        }
+        if let Ok(t) = parse_slack_ts(&m.origin.ts.0) {
+            let local = t.with_timezone(&Local);
+            *per_day
+                .entry(local.format("%Y-%m-%d").to_string())
+                .or_default() += 1;
+            hour_of_week[local.weekday().num_days_from_monday() as usize][local.hour() as usize] +=
+                1;
+        }
+        for r in m.content.reactions.iter().flatten() {
+            *reactions.entry(r.name.0.clone()).or_default() += r.count as u64;
+            for u in &r.users {
+                *reactors.entry(u.0.clone()).or_default() += 1;
+            }
+        }
+    }
+
+    latencies.sort_unstable();
+    let median_reply_latency_secs = latencies.get(latencies.len() / 2).copied();
+    let total = (messages.len() + replies.len()) as u64;
+
+    let mut top_reactions: Vec<ReactionCount> = reactions
+        .into_iter()
+        .map(|(name, count)| ReactionCount { name, count })
+        .collect();
+    top_reactions.sort_by(|a, b| b.count.cmp(&a.count).then(a.name.cmp(&b.name)));
+    top_reactions.truncate(top);
+
+    ChannelStats {
+        channel: channel.to_string(),
+        after: after.map(String::from),
+        before: before.map(String::from),
+        messages: messages.len() as u64,
+        replies: replies.len() as u64,
+        threads: threads.len() as u64,
+        median_reply_latency_secs,
+        bot_messages,
+        human_messages: total - bot_messages,
+        bot_share: if total > 0 {
+            bot_messages as f64 / total as f64
+        } else {
+            0.0
+        },
+        per_user: top_counts(per_user, top),
+        per_day: per_day
+            .into_iter()
+            .map(|(date, messages)| DayCount { date, messages })
+            .collect(),
+        hour_of_week,
+        top_reactions,
+        top_reactors: top_counts(reactors, top),
+    }
+}
+
+fn top_counts(counts: HashMap<String, u64>, top: usize) -> Vec<UserCount> {
+    let mut list: Vec<UserCount> = counts
+        .into_iter()
+        .map(|(user, count)| UserCount {
+            user,
+            name: None,
+            count,
+        })
+        .collect();
+    list.sort_by(|a, b| b.count.cmp(&a.count).then(a.user.cmp(&b.user)));
+    list.truncate(top);
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn message(value: serde_json::Value) -> SlackHistoryMessage {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_scale() {
+        assert_eq!(scale(0, 10, 4), 0);
+        assert_eq!(scale(5, 0, 4), 0);
+        assert_eq!(scale(10, 10, 4), 4);
+        assert_eq!(scale(5, 10, 4), 2);
+        // Any activity shows at least one step
+        assert_eq!(scale(1, 1000, 4), 1);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(45), "45s");
+        assert_eq!(format_duration(150), "2m");
+        assert_eq!(format_duration(3 * 3600 + 5 * 60), "3h05m");
+        assert_eq!(format_duration(2 * 86400 + 7200), "2d2h");
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let messages = vec![
+            message(json!({"ts": "1705320000.000100", "user": "U1", "text": "a",
+                           "reply_count": 2, "thread_ts": "1705320000.000100",
+                           "reactions": [{"name": "eyes", "count": 2, "users": ["U2", "U3"]}]})),
+            message(json!({"ts": "1705320600.000000", "user": "U1", "text": "b",
+                           "reply_count": 1, "thread_ts": "1705320600.000000"})),
+            message(
+                json!({"ts": "1705579200.000000", "bot_id": "B1", "text": "deploy",
+                           "subtype": "bot_message"}),
+            ),
+            // A broadcast reply shows up in history as well as in its thread
+            message(
+                json!({"ts": "1705320900.000000", "user": "U2", "text": "bc",
+                           "thread_ts": "1705320000.000100", "subtype": "thread_broadcast"}),
+            ),
+        ];
+        let threads = vec![
+            (
+                SlackTs::new("1705320000.000100".to_string()),
+                vec![
+                    message(
+                        json!({"ts": "1705320060.000100", "user": "U2", "text": "r1",
+                                   "thread_ts": "1705320000.000100",
+                                   "reactions": [{"name": "+1", "count": 1, "users": ["U3"]}]}),
+                    ),
+                    message(
+                        json!({"ts": "1705320900.000000", "user": "U2", "text": "bc",
+                                   "thread_ts": "1705320000.000100"}),
+                    ),
+                ],
+            ),
+            (
+                SlackTs::new("1705320600.000000".to_string()),
+                vec![message(json!({"ts": "1705324200.000000", "user": "U3",
+                                    "text": "r2", "thread_ts": "1705320600.000000"}))],
+            ),
+        ];
+
+        let stats = aggregate("C1", Some("7d"), None, &messages, &threads, 2);
+        assert_eq!(stats.messages, 4);
+        assert_eq!(stats.replies, 2);
+        assert_eq!(stats.threads, 2);
+        assert_eq!(stats.bot_messages, 1);
+        assert_eq!(stats.human_messages, 5);
+        assert!((stats.bot_share - 1.0 / 6.0).abs() < 1e-9);
+        // First replies after 60s and 3600s; the upper median of two
+        assert_eq!(stats.median_reply_latency_secs, Some(3600));
+
+        let posters: Vec<(&str, u64)> = stats
+            .per_user
+            .iter()
+            .map(|u| (u.user.as_str(), u.count))
+            .collect();
+        // The broadcast counts once; ties go by ID
+        assert_eq!(posters, [("U1", 2), ("U2", 2)]);
+        let reactions: Vec<(&str, u64)> = stats
+            .top_reactions
+            .iter()
+            .map(|r| (r.name.as_str(), r.count))
+            .collect();
+        assert_eq!(reactions, [("eyes", 2), ("+1", 1)]);
+        assert_eq!(stats.top_reactors[0].user, "U3");
+        assert_eq!(stats.top_reactors[0].count, 2);
+
+        // Days and hours are local time, so only check the totals
+        assert_eq!(stats.per_day.iter().map(|d| d.messages).sum::<u64>(), 6);
+        assert_eq!(stats.hour_of_week.iter().flatten().sum::<u64>(), 6);
+        assert_eq!(stats.after.as_deref(), Some("7d"));
+    }
+
+    #[test]
+    fn test_aggregate_empty() {
+        let stats = aggregate("C1", None, None, &[], &[], 5);
+        assert_eq!(stats.messages, 0);
+        assert_eq!(stats.bot_share, 0.0);
+        assert_eq!(stats.median_reply_latency_secs, None);
+        assert!(stats.per_day.is_empty());
+    }
+}
@@ -0,0 +1,312 @@
+use crate::client::{Client, HyperConnector};
+use crate::commands::files::upload_bytes;
+use crate::commands::messages::MAX_MESSAGE_CHARS;
+use crate::error::Result;
+use crate::mrkdwn;
+use crate::output::{HumanReadable, Output};
+use colored::Colorize;
+use serde::Serialize;
+use slack_morphism::prelude::*;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncBufReadExt;
+
+/// Room left in the message for the title and status line around the code block.
+const TAIL_BUDGET: usize = MAX_MESSAGE_CHARS - 500;
+
+#[derive(Debug, Serialize)]
+pub struct StreamedLog {
+    pub channel: String,
+    pub ts: String,
+    pub lines: usize,
+    /// Stopped with ctrl-c before stdin ended
+    pub interrupted: bool,
+    /// Full log uploaded with `--upload`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+}
+
+impl HumanReadable for StreamedLog {
+    fn print_human(&self) {
+        let state = if self.interrupted {
+            "Stream stopped".yellow()
+        } else {
+            "Stream finished".green()
+        };
+        println!("{} in {} ({} lines)", state, self.channel, self.lines);
+        println!("  ts: {}", self.ts.dimmed());
+        if let Some(file) = &self.file {
+            println!("  log: {}", file.dimmed());
+        }
+    }
+}
+
+/// Options for `messages stream`.
+#[derive(Debug)]
+pub struct StreamOptions {
+    /// Heading above the log tail
+    pub title: Option<String>,
+    /// Lines of the tail shown in the message
+    pub lines: usize,
+    /// Minimum time between message updates
+    pub interval: Duration,
+    /// Upload the complete log into the message's thread at the end
+    pub upload: bool,
+}
+
+/// Post a message and keep it updated with the tail of stdin until stdin ends (or ctrl-c),
+/// then finish with a status line and optionally upload the whole log. Input that isn't
+/// UTF-8 is decoded lossily; a read error still finishes the message before returning.
+pub async fn stream(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    opts: &StreamOptions,
+) -> Result<()> {
+    let session = client.session();
+    let channel_id = client.resolve_channel(channel).await?;
+    let started = Instant::now();
+
+    let mut log = Log {
+        title: opts.title.clone(),
+        tail: VecDeque::new(),
+        max_lines: opts.lines,
+        full: String::new(),
+        count: 0,
+    };
+
+    let request = SlackApiChatPostMessageRequest::new(
+        channel_id.clone(),
+        SlackMessageContent::new().with_text(log.render("_waiting for output…_")),
+    );
+    let ts = session.chat_post_message(&request).await?.ts;
+    output.status(&format!(
+        "streaming stdin to {}... (ctrl-c to stop)",
+        channel
+    ));
+
+    let mut stdin = tokio::io::BufReader::new(tokio::io::stdin());
+    let mut line = Vec::new();
+    let mut ticker = tokio::time::interval(opts.interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut stop = std::pin::pin!(tokio::signal::ctrl_c());
+    let mut dirty = false;
+    let mut interrupted = false;
+    let mut failure = None;
+
+    loop {
+        tokio::select! {
+            // Partial reads stay in `line` if another branch wins, so this is cancel safe
+            read = stdin.read_until(b'\n', &mut line) => match read {
+                Ok(0) => break,
+                Ok(_) => {
+                    log.push(decode_line(&line));
+                    line.clear();
+                    dirty = true;
+                }
+                Err(e) => {
+                    failure = Some(e);
+                    break;
+                }
+            },
+            _ = ticker.tick() => {
+                if dirty {
+                    let text = log.render(&format!("_running… {} lines_", log.count));
+                    // A failed update (rate limit, network blip) shouldn't stop the stream
+                    if let Err(e) = update(&session, &channel_id, &ts, text).await {
+                        output.error(&format!("update failed: {}", e));
+                    }
+                    dirty = false;
+                }
+            }
+            _ = &mut stop => {
+                interrupted = true;
+                break;
+            }
+        }
+    }
+
+    let elapsed = started.elapsed().as_secs();
+    let status = format!(
+        "{} after {}m{:02}s, {} lines",
+        match (&failure, interrupted) {
+            (Some(_), _) => ":x: Failed reading input",
+            (None, true) => ":warning: Stopped",
+            (None, false) => ":white_check_mark: Finished",
+        },
+        elapsed / 60,
+        elapsed % 60,
+        log.count
+    );
+    update(&session, &channel_id, &ts, log.render(&status)).await?;
+    if let Some(e) = failure {
+        return Err(e.into());
+    }
+
+    let file = if opts.upload && !log.full.is_empty() {
+        let uploaded = upload_bytes(
+            client,
+            "log.txt".to_string(),
+            log.full.into_bytes(),
+            "text/plain".to_string(),
+            Some(channel_id.clone()),
+            Some(&ts.0),
+            None,
+        )
+        .await?;
+        Some(uploaded.id)
+    } else {
+        None
+    };
+
+    let result = StreamedLog {
+        channel: channel_id.0,
+        ts: ts.0,
+        lines: log.count,
+        interrupted,
+        file,
+    };
+    output.print(&result);
+
+    Ok(())
+}
+
+async fn update(
+    session: &SlackClientSession<'_, HyperConnector>,
+    channel_id: &SlackChannelId,
+    ts: &SlackTs,
+    text: String,
+) -> Result<()> {
+    let request = SlackApiChatUpdateRequest::new(
+        channel_id.clone(),
+        SlackMessageContent::new().with_text(text),
+        ts.clone(),
+    );
+    session.chat_update(&request).await?;
+    Ok(())
+}
+
+struct Log {
+    title: Option<String>,
+    tail: VecDeque<String>,
+    max_lines: usize,
+    full: String,
+    count: usize,
+}
+
+impl Log {
+    fn push(&mut self, line: String) {
+        self.full.push_str(&line);
+        self.full.push('\n');
+        self.count += 1;
+        self.tail.push_back(line);
+        if self.tail.len() > self.max_lines {
+            self.tail.pop_front();
+        }
+    }
+
+    /// Title, the newest lines that fit in a code block, and a status line.
+    fn render(&self, status: &str) -> String {
+        let mut lines: Vec<String> = Vec::new();
+        let mut size = 0;
+        for line in self.tail.iter().rev() {
+            let line = escape(line);
+            size += line.chars().count() + 1;
+            if size > TAIL_BUDGET && !lines.is_empty() {
+                break;
+            }
+            lines.push(line);
+        }
+        lines.reverse();
+
+        let mut text = String::new();
+        if let Some(title) = &self.title {
+            text.push_str(&format!("*{}*\n", title));
+        }
+        if !lines.is_empty() {
+            text.push_str(&format!("```\n{}\n```\n", lines.join("\n")));
+        }
+        text.push_str(status);
+        text
+    }
+}
+
+/// One line of input without its line ending, decoding anything that isn't UTF-8 lossily.
+fn decode_line(bytes: &[u8]) -> String {
+    let line = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    String::from_utf8_lossy(line).into_owned()
+}
+
+/// Slack control characters, plus fences that would close the code block early.
+fn escape(line: &str) -> String {
+    let line: String = line.chars().take(TAIL_BUDGET).collect();
+    mrkdwn::escape(&line).replace("```", "`\u{200b}``")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(max_lines: usize, title: Option<&str>) -> Log {
+        Log {
+            title: title.map(String::from),
+            tail: VecDeque::new(),
+            max_lines,
+            full: String::new(),
+            count: 0,
+        }
+    }
+
+    #[test]
+    fn test_render_empty() {
+        let log = log(10, Some("Deploy"));
+        assert_eq!(log.render("_waiting_"), "*Deploy*\n_waiting_");
+    }
+
+    #[test]
+    fn test_render_keeps_last_lines() {
+        let mut log = log(2, None);
+        for line in ["one", "two", "three"] {
+            log.push(line.to_string());
+        }
+        assert_eq!(log.count, 3);
+        assert_eq!(log.full, "one\ntwo\nthree\n");
+        assert_eq!(log.render("done"), "```\ntwo\nthree\n```\ndone");
+    }
+
+    #[test]
+    fn test_render_fits_budget() {
+        let mut log = log(100, None);
+        for i in 0..100 {
+            log.push(format!("{:03} {}", i, "x".repeat(96)));
+        }
+        let text = log.render("done");
+        assert!(text.chars().count() <= TAIL_BUDGET + 20);
+        // The newest lines are the ones kept
+        assert!(text.contains("099 "));
+        assert!(!text.contains("000 "));
+    }
+
+    #[test]
+    fn test_render_truncates_one_long_line() {
+        let mut log = log(10, None);
+        log.push("y".repeat(TAIL_BUDGET * 2));
+        let text = log.render("done");
+        assert_eq!(text.matches('y').count(), TAIL_BUDGET);
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a < b && c > d"), "a &lt; b &amp;&amp; c &gt; d");
+        assert!(!escape("```rust").contains("```"));
+    }
+
+    #[test]
+    fn test_decode_line() {
+        assert_eq!(decode_line(b"plain\n"), "plain");
+        assert_eq!(decode_line(b"crlf\r\n"), "crlf");
+        assert_eq!(decode_line(b"last"), "last");
+        assert_eq!(decode_line(b"bad \xff byte\n"), "bad \u{fffd} byte");
+    }
+}
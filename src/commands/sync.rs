@@ -0,0 +1,144 @@
+use crate::client::Client;
+use crate::error::Result;
+use crate::mirror::Mirror;
+use crate::output::{HumanReadable, Output};
+use crate::timeparse::parse_slack_ts;
+use colored::Colorize;
+use futures::TryStreamExt;
+use serde::Serialize;
+use slack_morphism::prelude::*;
+use std::path::Path;
+
+/// Thread parents up to this many days older than the channel cursor are re-checked
+/// for new replies, since replies don't show up in `conversations.history`.
+const THREAD_LOOKBACK_DAYS: i64 = 7;
+
+#[derive(Debug, Serialize)]
+pub struct SyncSummary {
+    pub channel: String,
+    pub name: String,
+    pub new_messages: usize,
+    pub new_replies: usize,
+    pub cursor_ts: Option<String>,
+}
+
+impl HumanReadable for SyncSummary {
+    fn print_human(&self) {
+        println!(
+            "#{} {} new messages, {} new replies",
+            self.name.bold(),
+            self.new_messages,
+            self.new_replies
+        );
+        if let Some(ts) = &self.cursor_ts {
+            println!("  cursor: {}", ts.dimmed());
+        }
+    }
+}
+
+/// Mirror users, channel info, messages, threads and reactions into the local database.
+/// Only history newer than each channel's last cursor is fetched.
+pub async fn sync(client: &Client, output: &Output, channels: &[String], db: &Path) -> Result<()> {
+    let session = client.session();
+    let mut mirror = Mirror::open(db)?;
+    let channel_ids = client.resolve_channels(channels).await?;
+
+    output.status("Syncing users...");
+    let request = SlackApiUsersListRequest::new().with_limit(200);
+    let scroller = request.scroller();
+    let mut stream = scroller.to_items_stream(&session);
+    let mut users = Vec::new();
+    while let Some(batch) = stream.try_next().await? {
+        users.extend(batch);
+    }
+    let tx = mirror.begin()?;
+    for user in &users {
+        Mirror::upsert_user(&tx, user)?;
+    }
+    tx.commit()?;
+
+    let mut summaries = Vec::new();
+    for channel_id in &channel_ids {
+        let request = SlackApiConversationsInfoRequest::new(channel_id.clone());
+        let info = session.conversations_info(&request).await?.channel;
+        let name = info.name.clone().unwrap_or_else(|| channel_id.0.clone());
+        output.status(&format!("Syncing #{}...", name));
+
+        let cursor = mirror.cursor(&channel_id.0)?;
+        let mut request = SlackApiConversationsHistoryRequest::new()
+            .with_channel(channel_id.clone())
+            .with_limit(200);
+        if let Some(cursor) = cursor.as_deref().and_then(|c| parse_slack_ts(c).ok()) {
+            let lookback = cursor.timestamp() - THREAD_LOOKBACK_DAYS * 86400;
+            request = request.with_oldest(SlackTs::new(format!("{}.000000", lookback)));
+        }
+        let scroller = request.scroller();
+        let mut stream = scroller.to_items_stream(&session);
+        let mut messages = Vec::new();
+        while let Some(batch) = stream.try_next().await? {
+            messages.extend(batch);
+        }
+
+        // Refetch only threads whose latest reply moved since the last sync,
+        // starting from the last reply we already have.
+        let mut replies = Vec::new();
+        for parent in messages
+            .iter()
+            .filter(|m| m.parent.reply_count.is_some_and(|c| c > 0))
+        {
+            let stored = mirror.latest_reply(&channel_id.0, &parent.origin.ts.0)?;
+            if stored.as_ref() == parent.parent.latest_reply.as_ref().map(|t| &t.0) {
+                continue;
+            }
+            let mut request = SlackApiConversationsRepliesRequest::new(
+                channel_id.clone(),
+                parent.origin.ts.clone(),
+            )
+            .with_limit(200);
+            if let Some(stored) = stored {
+                request = request.with_oldest(SlackTs::new(stored));
+            }
+            let scroller = request.scroller();
+            let mut stream = scroller.to_items_stream(&session);
+            while let Some(batch) = stream.try_next().await? {
+                replies.extend(
+                    batch
+                        .into_iter()
+                        .filter(|m| m.origin.ts != parent.origin.ts),
+                );
+            }
+        }
+
+        let is_new = |m: &&SlackHistoryMessage| {
+            cursor
+                .as_deref()
+                .is_none_or(|c| parse_slack_ts(&m.origin.ts.0).ok() > parse_slack_ts(c).ok())
+        };
+        let new_messages = messages.iter().filter(is_new).count();
+        let new_cursor = messages
+            .iter()
+            .map(|m| m.origin.ts.0.clone())
+            .max_by_key(|ts| parse_slack_ts(ts).ok())
+            .or(cursor);
+
+        let tx = mirror.begin()?;
+        Mirror::upsert_channel(&tx, &info)?;
+        for message in messages.iter().chain(&replies) {
+            Mirror::upsert_message(&tx, &channel_id.0, message)?;
+        }
+        Mirror::set_cursor(&tx, &channel_id.0, new_cursor.as_deref())?;
+        tx.commit()?;
+
+        summaries.push(SyncSummary {
+            channel: channel_id.0.clone(),
+            name,
+            new_messages,
+            new_replies: replies.len(),
+            cursor_ts: new_cursor,
+        });
+    }
+
+    output.print_list(&summaries, &format!("Synced to {}", db.display()));
+
+    Ok(())
+}
@@ -41,6 +41,7 @@ pub async fn test(client: &Client, output: &Output) -> Result<()> {
 }
 
 const READ_SCOPES: &[&str] = &[
+    "bookmarks:read",
     "channels:history",
     "channels:read",
     "files:read",
@@ -52,17 +53,23 @@ const READ_SCOPES: &[&str] = &[
     "mpim:read",
     "pins:read",
     "reactions:read",
+    "reminders:read",
     "search:read",
     "users:read",
     "users:read.email",
 ];
 
 const WRITE_SCOPES: &[&str] = &[
+    "bookmarks:write",
+    "channels:write",
     "chat:write",
     "files:write",
+    "groups:write",
     "im:write",
+    "mpim:write",
     "pins:write",
     "reactions:write",
+    "reminders:write",
     "users.profile:write",
 ];
 
@@ -235,11 +242,12 @@ pub fn create(output: &Output, write: bool, watch: bool) -> Result<()> {
         println!();
         println!("{}", "─".repeat(60));
         println!("  Read scopes: channels, groups, im, mpim (read + history),");
-        println!("  files:read, search:read, users:read, users:read.email,");
-        println!("  pins:read, reactions:read");
+        println!("  bookmarks:read, files:read, search:read, users:read,");
+        println!("  users:read.email, pins:read, reactions:read, reminders:read");
         if write {
-            println!("  Write scopes: chat:write, files:write, im:write,");
-            println!("  pins:write, reactions:write, users.profile:write");
+            println!("  Write scopes: bookmarks:write, channels:write, chat:write,");
+            println!("  files:write, groups:write, im:write, mpim:write, pins:write,");
+            println!("  reactions:write, reminders:write, users.profile:write");
         }
         if watch {
             println!("  Events: messages, reactions, members, files, channels,");
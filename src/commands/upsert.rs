@@ -0,0 +1,161 @@
+use crate::client::Client;
+use crate::commands::messages::{MAX_MESSAGE_CHARS, MessageBody, to_json};
+use crate::error::{Result, SlackCliError};
+use crate::output::{HumanReadable, Output};
+use colored::Colorize;
+use serde::Serialize;
+use slack_morphism::prelude::*;
+
+/// Message metadata event type marking messages owned by `messages upsert`.
+const UPSERT_EVENT_TYPE: &str = "slackline_upsert";
+/// History pages (of 200) searched for the keyed message, newest first.
+const UPSERT_SCAN_PAGES: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpsertAction {
+    Posted,
+    Updated,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpsertedMessage {
+    pub key: String,
+    pub channel: String,
+    pub ts: String,
+    pub action: UpsertAction,
+    /// Thread reply added with `--thread-append`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_ts: Option<String>,
+}
+
+impl HumanReadable for UpsertedMessage {
+    fn print_human(&self) {
+        let action = match self.action {
+            UpsertAction::Posted => "Posted".green(),
+            UpsertAction::Updated => "Updated".yellow(),
+        };
+        println!("{} {} in {}", action, self.key.bold(), self.channel);
+        println!("  ts: {}", self.ts.dimmed());
+    }
+}
+
+/// Post a message tagged with `key`, or update the one already posted with that key.
+/// The key lives in the message's metadata, so any machine can update it later.
+/// With `thread_append`, each update also adds the new text as a thread reply.
+pub async fn upsert(
+    client: &Client,
+    output: &Output,
+    channel: &str,
+    key: &str,
+    body: &MessageBody,
+    thread_append: bool,
+) -> Result<()> {
+    if body.is_long() {
+        return Err(SlackCliError::Config(format!(
+            "Message text is longer than {} characters",
+            MAX_MESSAGE_CHARS
+        )));
+    }
+    let channel_id = client.resolve_channel(channel).await?;
+    let metadata = serde_json::json!({
+        "event_type": UPSERT_EVENT_TYPE,
+        "event_payload": { "key": key },
+    });
+
+    let existing = find_keyed(client, &channel_id, key).await?;
+
+    let channel = channel_id.0.clone();
+    let (ts, action, log_ts) = match existing {
+        Some(ts) => {
+            let request = SlackApiChatUpdateRequest::new(
+                channel_id.clone(),
+                body.content(),
+                SlackTs::new(ts.clone()),
+            );
+            let mut request = to_json(&request)?;
+            request["metadata"] = metadata;
+            client.api_post("chat.update", &request).await?;
+
+            let mut log_ts = None;
+            if thread_append {
+                let reply = SlackApiChatPostMessageRequest::new(
+                    channel_id,
+                    SlackMessageContent::new().with_text(body.text.clone()),
+                )
+                .with_thread_ts(SlackTs::new(ts.clone()));
+                let response = client
+                    .api_post("chat.postMessage", &to_json(&reply)?)
+                    .await?;
+                log_ts = response["ts"].as_str().map(String::from);
+            }
+            (ts, UpsertAction::Updated, log_ts)
+        }
+        None => {
+            let request = SlackApiChatPostMessageRequest::new(channel_id, body.content());
+            let mut request = to_json(&request)?;
+            request["metadata"] = metadata;
+            let response = client.api_post("chat.postMessage", &request).await?;
+            let ts = response["ts"].as_str().unwrap_or_default().to_string();
+            (ts, UpsertAction::Posted, None)
+        }
+    };
+
+    let result = UpsertedMessage {
+        key: key.to_string(),
+        channel,
+        ts,
+        action,
+        log_ts,
+    };
+    output.print(&result);
+    output.success(&format!(
+        "Message {} {}",
+        key,
+        match action {
+            UpsertAction::Posted => "posted",
+            UpsertAction::Updated => "updated",
+        }
+    ));
+
+    Ok(())
+}
+
+/// Find the newest message in recent history whose upsert metadata carries `key`.
+async fn find_keyed(
+    client: &Client,
+    channel_id: &SlackChannelId,
+    key: &str,
+) -> Result<Option<String>> {
+    let mut cursor = String::new();
+    for _ in 0..UPSERT_SCAN_PAGES {
+        let mut params = vec![
+            ("channel", channel_id.0.as_str()),
+            ("include_all_metadata", "true"),
+            ("limit", "200"),
+        ];
+        if !cursor.is_empty() {
+            params.push(("cursor", &cursor));
+        }
+        let response = client.api_get("conversations.history", &params).await?;
+
+        let found = response["messages"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|m| {
+                m["metadata"]["event_type"] == UPSERT_EVENT_TYPE
+                    && m["metadata"]["event_payload"]["key"] == key
+            })
+            .and_then(|m| m["ts"].as_str());
+        if let Some(ts) = found {
+            return Ok(Some(ts.to_string()));
+        }
+
+        match response["response_metadata"]["next_cursor"].as_str() {
+            Some(next) if !next.is_empty() => cursor = next.to_string(),
+            _ => break,
+        }
+    }
+    Ok(None)
+}
@@ -72,7 +72,7 @@ impl HumanReadable for PresenceInfo {
     }
 }
 
-fn user_from_slack(u: SlackUser) -> UserInfo {
+pub fn user_from_slack(u: SlackUser) -> UserInfo {
     let profile = u.profile;
     UserInfo {
         id: u.id.0,
@@ -5,7 +5,7 @@ use serde::Serialize;
 use slack_morphism::prelude::*;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EventFilter {
@@ -705,6 +705,89 @@ async fn push_events_handler(
     Ok(())
 }
 
+fn check_app_token(app_token: &str) -> Result<()> {
+    if !app_token.starts_with("xapp-") {
+        return Err(SlackCliError::Config(
+            "SLACK_APP_TOKEN must start with 'xapp-'. App-level tokens are different from bot/user tokens."
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+struct WakeState {
+    channel: String,
+    notify: Arc<Notify>,
+}
+
+async fn wake_events_handler(
+    event: SlackPushEventCallback,
+    _client: Arc<SlackHyperClient>,
+    states: SlackClientEventsUserState,
+) -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let state_guard = states.read().await;
+    if let Some(state) = state_guard.get_user_state::<WakeState>() {
+        let (_, channel, _) = extract_event_info(&event.event);
+        if channel == Some(state.channel.as_str()) {
+            state.notify.notify_one();
+        }
+    }
+    Ok(())
+}
+
+/// A Socket Mode connection that signals on any event in one channel, so a poller
+/// can re-check right away instead of waiting for its next interval.
+pub struct ChannelWakeups {
+    listener: SlackClientSocketModeListener<HyperConnector>,
+    notify: Arc<Notify>,
+}
+
+impl ChannelWakeups {
+    pub async fn connect(
+        client: &crate::client::Client,
+        app_token: &str,
+        channel_id: &SlackChannelId,
+    ) -> Result<Self> {
+        check_app_token(app_token)?;
+
+        let notify = Arc::new(Notify::new());
+        let listener_environment = Arc::new(
+            SlackClientEventsListenerEnvironment::new(Arc::clone(client.inner()))
+                .with_error_handler(|err, _client, _states| {
+                    eprintln!("socket mode error: {err} ({err:?})");
+                    http::StatusCode::OK
+                })
+                .with_user_state(WakeState {
+                    channel: channel_id.0.clone(),
+                    notify: Arc::clone(&notify),
+                }),
+        );
+        let listener = SlackClientSocketModeListener::new(
+            &SlackClientSocketModeConfig::new(),
+            listener_environment,
+            SlackSocketModeListenerCallbacks::new().with_push_events(wake_events_handler),
+        );
+
+        let app_token = SlackApiToken::new(app_token.to_string().into());
+        listener
+            .listen_for(&app_token)
+            .await
+            .map_err(|e| SlackCliError::Api(format!("failed to connect socket mode: {e}")))?;
+        listener.start().await;
+
+        Ok(Self { listener, notify })
+    }
+
+    /// Wait for the next event in the channel.
+    pub async fn wait(&self) {
+        self.notify.notified().await;
+    }
+
+    pub async fn shutdown(&self) {
+        self.listener.shutdown().await;
+    }
+}
+
 /// Connect to Slack Socket Mode and stream events as JSONL to stdout.
 pub async fn listen(
     config: &crate::Config,
@@ -723,12 +806,7 @@ pub async fn listen(
         )
     })?;
 
-    if !app_token_str.starts_with("xapp-") {
-        return Err(SlackCliError::Config(
-            "SLACK_APP_TOKEN must start with 'xapp-'. App-level tokens are different from bot/user tokens."
-                .to_string(),
-        ));
-    }
+    check_app_token(app_token_str)?;
 
     let filters = if events.is_empty() {
         default_filters()
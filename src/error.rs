@@ -20,9 +20,18 @@ pub enum SlackCliError {
     #[error("Rate limited: {0}")]
     RateLimit(String),
 
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    #[error("Rejected: {0}")]
+    Rejected(String),
+
     #[error(transparent)]
     Http(#[from] slack_morphism::errors::SlackClientError),
 
+    #[error("Database error: {0}")]
+    Db(#[from] rusqlite::Error),
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
@@ -38,15 +47,56 @@ impl SlackCliError {
             Self::Api(_) | Self::Http(_) => "api",
             Self::Config(_) => "config",
             Self::RateLimit(_) => "rate_limit",
+            Self::Timeout(_) => "timeout",
+            Self::Rejected(_) => "rejected",
+            Self::Db(_) => "db",
             Self::Io(_) | Self::Other(_) => "generic",
         }
     }
 
+    /// Failures a long-running poll should ride out: rate limits, network errors and
+    /// Slack-side outages. Config, auth and not-found errors won't fix themselves.
+    pub fn is_transient(&self) -> bool {
+        use slack_morphism::errors::SlackClientError;
+        match self {
+            Self::RateLimit(_) | Self::Io(_) => true,
+            Self::Http(SlackClientError::ApiError(e)) => matches!(
+                e.code.as_str(),
+                "ratelimited"
+                    | "internal_error"
+                    | "fatal_error"
+                    | "service_unavailable"
+                    | "request_timeout"
+            ),
+            Self::Http(_) => true,
+            _ => false,
+        }
+    }
+
+    /// How long to wait before retrying a rate-limited call (Slack's Retry-After when it
+    /// sent one), or None if this isn't a rate limit.
+    pub fn rate_limit_wait(&self) -> Option<std::time::Duration> {
+        use slack_morphism::errors::SlackClientError;
+        const DEFAULT_WAIT: std::time::Duration = std::time::Duration::from_secs(5);
+        match self {
+            Self::RateLimit(_) => Some(DEFAULT_WAIT),
+            Self::Http(SlackClientError::RateLimitError(e)) => {
+                Some(e.retry_after.unwrap_or(DEFAULT_WAIT))
+            }
+            Self::Http(SlackClientError::ApiError(e)) if e.code == "ratelimited" => {
+                Some(DEFAULT_WAIT)
+            }
+            _ => None,
+        }
+    }
+
     pub fn exit_code(&self) -> i32 {
         match self {
             Self::Auth(_) => 2,
             Self::ChannelNotFound(_) | Self::UserNotFound(_) => 3,
             Self::RateLimit(_) => 4,
+            Self::Timeout(_) => 5,
+            Self::Rejected(_) => 6,
             _ => 1,
         }
     }
@@ -1,8 +1,12 @@
+pub mod blocks;
 pub mod client;
 pub mod commands;
 pub mod config;
 pub mod error;
+pub mod mirror;
+pub mod mrkdwn;
 pub mod output;
+pub mod permalink;
 pub mod timeparse;
 
 pub use client::Client as SlackClient;
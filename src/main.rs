@@ -1,7 +1,13 @@
-use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand};
 use clap_complete::Shell;
+use slackline::commands::approval::AwaitOptions;
+use slackline::commands::channels::{ChannelSort, HistoryOptions, ListFilters, MemberOptions};
+use slackline::commands::messages::{MessageBody, PostOptions, PurgeOptions};
+use slackline::commands::stream::StreamOptions;
 use slackline::commands::watch::EventFilter;
+use slackline::permalink::parse_permalink;
 use slackline::{Config, Output, SlackClient, commands};
+use std::path::{Path, PathBuf};
 
 const ABOUT: &str = "Slack CLI.";
 
@@ -26,6 +32,10 @@ struct Cli {
     #[arg(long, short, global = true)]
     quiet: bool,
 
+    /// Local mirror database for `sync`, `db` and --offline [default: ~/.local/share/slackline/mirror.db]
+    #[arg(long, env = "SLACKLINE_DB", global = true)]
+    db: Option<String>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -62,6 +72,16 @@ enum Commands {
         #[command(subcommand)]
         command: MeCommands,
     },
+    /// Add, list, complete and delete reminders
+    Reminders {
+        #[command(subcommand)]
+        command: ReminderCommands,
+    },
+    /// Post reaction polls and tally their votes
+    Polls {
+        #[command(subcommand)]
+        command: PollCommands,
+    },
     /// Search messages across workspace
     Search {
         #[command(subcommand)]
@@ -72,6 +92,35 @@ enum Commands {
         #[command(subcommand)]
         command: TokenCommands,
     },
+    /// Archive channels in Slack's export format (resumable)
+    Export {
+        /// Channels to export (names or IDs)
+        #[arg(required = true)]
+        channels: Vec<String>,
+        /// Output directory
+        #[arg(long, short)]
+        out: String,
+        /// Only messages after this time (ISO timestamp, relative like 1h/30m/2d, or 'today')
+        #[arg(long)]
+        after: Option<String>,
+        /// Only messages before this time (ISO timestamp, relative like 1h/30m/2d, or 'today')
+        #[arg(long)]
+        before: Option<String>,
+        /// Also download files referenced by messages
+        #[arg(long)]
+        files: bool,
+    },
+    /// Mirror channels into a local SQLite database (incremental)
+    Sync {
+        /// Channels to mirror (names or IDs)
+        #[arg(required = true)]
+        channels: Vec<String>,
+    },
+    /// Query the local mirror
+    Db {
+        #[command(subcommand)]
+        command: DbCommands,
+    },
     /// Stream real-time events via Socket Mode (JSONL to stdout)
     Watch {
         /// Event types to stream (comma-separated: message,reaction,dm,channel,file,member,status,all)
@@ -109,6 +158,24 @@ enum ChannelCommands {
         /// Max channels to return [default: 100]
         #[arg(long, short)]
         limit: Option<u16>,
+        /// Conversation types (comma-separated: public,private,mpim,im) [default: public]
+        #[arg(long, value_delimiter = ',', value_parser = parse_conversation_type)]
+        types: Vec<slack_morphism::prelude::SlackConversationType>,
+        /// Include archived channels
+        #[arg(long)]
+        include_archived: bool,
+        /// Only channels whose name matches this regex
+        #[arg(long)]
+        name: Option<String>,
+        /// Only channels with at least this many members
+        #[arg(long)]
+        min_members: Option<u64>,
+        /// Only channels created after this time (ISO timestamp, relative like 1h/30m/2d, or 'today')
+        #[arg(long)]
+        created_after: Option<String>,
+        /// Sort by name, members (most first) or created (newest first)
+        #[arg(long, value_parser = parse_channel_sort)]
+        sort: Option<ChannelSort>,
     },
     /// Get channel details by ID
     Info {
@@ -131,6 +198,21 @@ enum ChannelCommands {
         /// Resolve user IDs to usernames and real names
         #[arg(long)]
         enrich: bool,
+        /// Fetch thread replies and show them under their parent
+        #[arg(long, conflicts_with = "offline")]
+        with_replies: bool,
+        /// Read from the local mirror (see `sync`) instead of the API
+        #[arg(long)]
+        offline: bool,
+        /// Keep polling for new messages (and replies with --with-replies) until ctrl-c
+        #[arg(long, short, conflicts_with_all = ["offline", "before"])]
+        follow: bool,
+        /// Polling interval for --follow (e.g. 10s, 1m)
+        #[arg(long, default_value = "10s", value_parser = parse_interval)]
+        interval: std::time::Duration,
+        /// Return complete messages (blocks, attachments, files, edits, reactions...)
+        #[arg(long, conflicts_with_all = ["enrich", "with_replies", "offline", "follow"])]
+        full: bool,
     },
     /// List channel member user IDs
     Members {
@@ -139,6 +221,37 @@ enum ChannelCommands {
         /// Max members to return [default: 100]
         #[arg(long, short)]
         limit: Option<u16>,
+        /// Resolve usernames, real names, titles, timezones and bot/deleted flags
+        #[arg(long)]
+        enrich: bool,
+        /// Include online/away presence
+        #[arg(long)]
+        presence: bool,
+        /// Hide bot users (implies --enrich lookups)
+        #[arg(long)]
+        exclude_bots: bool,
+        /// Hide deactivated users (implies --enrich lookups)
+        #[arg(long)]
+        exclude_deleted: bool,
+    },
+    /// Activity statistics: posters, days, hours, threads, reactions, bots
+    Stats {
+        /// Channel name or ID
+        channel: String,
+        /// Only messages after this time (ISO timestamp, relative like 1h/30m/2d, or 'today') [default: 30d]
+        #[arg(long, default_value = "30d")]
+        after: String,
+        /// Only messages before this time (ISO timestamp, relative like 1h/30m/2d, or 'today')
+        #[arg(long)]
+        before: Option<String>,
+        /// Number of entries in top-N lists [default: 10]
+        #[arg(long, default_value = "10")]
+        top: usize,
+    },
+    /// Manage channel bookmarks
+    Bookmarks {
+        #[command(subcommand)]
+        command: BookmarkCommands,
     },
     /// List pinned messages in channel
     Pins {
@@ -155,6 +268,127 @@ enum ChannelCommands {
         /// Channel name or ID
         channel: String,
     },
+    /// Create a channel
+    Create {
+        /// Channel name (lowercase, no spaces)
+        name: String,
+        /// Create a private channel
+        #[arg(long)]
+        private: bool,
+    },
+    /// Archive a channel
+    Archive {
+        /// Channel name or ID
+        channel: String,
+    },
+    /// Unarchive a channel
+    Unarchive {
+        /// Channel name or ID
+        channel: String,
+    },
+    /// Rename a channel
+    Rename {
+        /// Channel name or ID
+        channel: String,
+        /// New channel name
+        name: String,
+    },
+    /// Set the channel topic
+    SetTopic {
+        /// Channel name or ID
+        channel: String,
+        /// Topic text
+        topic: String,
+    },
+    /// Set the channel purpose
+    SetPurpose {
+        /// Channel name or ID
+        channel: String,
+        /// Purpose text
+        purpose: String,
+    },
+    /// Invite users to a channel
+    Invite {
+        /// Channel name or ID
+        channel: String,
+        /// Users to invite (IDs, names, or @names)
+        #[arg(required = true)]
+        users: Vec<String>,
+    },
+    /// Remove a user from a channel
+    Kick {
+        /// Channel name or ID
+        channel: String,
+        /// User ID, name, or @name
+        user: String,
+    },
+    /// Mark a channel or DM as read
+    Mark {
+        /// Channel name or ID (DM IDs work too)
+        channel: String,
+        /// Mark as read up to this message timestamp [default: the latest message]
+        #[arg(long)]
+        ts: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum BookmarkCommands {
+    /// List bookmarks (returns id, channel, title, link, emoji, type)
+    List {
+        /// Channel names or IDs
+        #[arg(required = true)]
+        channels: Vec<String>,
+    },
+    /// Add a bookmark
+    Add {
+        /// Channel name or ID
+        channel: String,
+        /// Bookmark title
+        title: String,
+        /// Bookmark URL
+        link: String,
+        /// Emoji (e.g., :book:)
+        #[arg(long, short)]
+        emoji: Option<String>,
+        /// Bookmark type [default: link]
+        #[arg(long = "type", default_value = "link")]
+        kind: String,
+    },
+    /// Edit a bookmark
+    Edit {
+        /// Channel name or ID
+        channel: String,
+        /// Bookmark ID or title
+        bookmark: String,
+        /// New title
+        #[arg(long)]
+        title: Option<String>,
+        /// New URL
+        #[arg(long)]
+        link: Option<String>,
+        /// New emoji (e.g., :book:)
+        #[arg(long, short)]
+        emoji: Option<String>,
+    },
+    /// Remove a bookmark
+    Remove {
+        /// Channel name or ID
+        channel: String,
+        /// Bookmark ID or title
+        bookmark: String,
+    },
+    /// Make a channel's bookmarks match a JSON file (idempotent)
+    Apply {
+        /// Channel name or ID
+        channel: String,
+        /// JSON array of {title, link?, emoji?, type?} ('-' for stdin)
+        #[arg(long)]
+        from_file: String,
+        /// Remove bookmarks not in the file
+        #[arg(long)]
+        prune: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -174,6 +408,9 @@ enum UserCommands {
     Search {
         /// Search query (matches against name, username, email)
         query: String,
+        /// Read from the local mirror (see `sync`) instead of the API
+        #[arg(long)]
+        offline: bool,
     },
     /// Check if user is online or away
     Presence {
@@ -182,45 +419,238 @@ enum UserCommands {
     },
 }
 
+/// Message body arguments shared by the commands that post or edit a message.
+#[derive(Args)]
+struct BodyArgs {
+    /// Message text ('-' to read from stdin)
+    #[arg(required_unless_present_any = ["blocks", "file"])]
+    text: Option<String>,
+    /// Read the message text from a file
+    #[arg(long, conflicts_with = "text")]
+    file: Option<String>,
+    /// Convert the text from Markdown (CommonMark) to Slack mrkdwn
+    #[arg(long)]
+    markdown: bool,
+    /// Block Kit JSON file ('-' for stdin): a blocks array or a Block Kit Builder payload
+    #[arg(long)]
+    blocks: Option<String>,
+    /// Notification fallback text when sending --blocks
+    #[arg(long = "text", conflicts_with_all = ["text", "file"])]
+    fallback: Option<String>,
+}
+
+impl BodyArgs {
+    fn into_body(self) -> slackline::error::Result<MessageBody> {
+        MessageBody::new(
+            self.text.or(self.fallback),
+            self.file.as_deref(),
+            self.markdown,
+            self.blocks.as_deref(),
+        )
+    }
+}
+
 #[derive(Subcommand)]
 enum MessageCommands {
     /// Read thread replies (use ts from parent message)
     Replies {
-        /// Channel ID (e.g., C1RCG46LS)
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
         channel: String,
         /// Thread timestamp from parent message (e.g., 1769415774.159039)
         thread_ts: String,
         /// Max replies to return [default: 100]
         #[arg(long, short)]
         limit: Option<u16>,
+        /// Read from the local mirror (see `sync`) instead of the API
+        #[arg(long)]
+        offline: bool,
+        /// Return complete messages (blocks, attachments, files, edits, reactions...)
+        #[arg(long, conflicts_with = "offline")]
+        full: bool,
+    },
+    /// Get one message with all its fields (also finds thread replies)
+    Get {
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
+        channel: String,
+        /// Message timestamp (e.g., 1769415774.159039)
+        ts: String,
     },
     /// Get shareable URL for a message
     Permalink {
-        /// Channel ID (e.g., C1RCG46LS)
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
         channel: String,
         /// Message timestamp (e.g., 1769415774.159039)
         message_ts: String,
     },
     /// Get reactions on a message
     Reactions {
-        /// Channel ID (e.g., C1RCG46LS)
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
+        channel: String,
+        /// Message timestamp (e.g., 1769415774.159039)
+        ts: String,
+    },
+    /// Share a message in another channel or a DM as a link that unfurls into a preview
+    Forward {
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
         channel: String,
         /// Message timestamp (e.g., 1769415774.159039)
         ts: String,
+        /// Destination: a channel, or @user / user ID for a DM
+        #[arg(long)]
+        to: String,
+        /// Text to post above the link
+        #[arg(long)]
+        comment: Option<String>,
+        /// Also quote the original text and its author
+        #[arg(long)]
+        quote: bool,
+    },
+    /// Wait for a message to be approved by reaction or thread reply (exits non-zero on rejection or timeout)
+    Await {
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
+        channel: String,
+        /// Message timestamp (e.g., 1769415774.159039)
+        ts: String,
+        /// Reaction that approves (e.g. white_check_mark)
+        #[arg(long, required_unless_present = "reply_matches")]
+        reaction: Option<String>,
+        /// Reaction that rejects
+        #[arg(long, default_value = "x")]
+        reject: String,
+        /// Only count reactions and replies from this user (repeatable; default: anyone but you)
+        #[arg(long)]
+        from: Vec<String>,
+        /// Approve on a thread reply matching this regex (e.g. '(?i)^(go|lgtm)')
+        #[arg(long)]
+        reply_matches: Option<String>,
+        /// Give up after this long (e.g. 30m, 2h)
+        #[arg(long, value_parser = parse_interval)]
+        timeout: Option<std::time::Duration>,
+        /// Polling interval (events also wake it up when SLACK_APP_TOKEN is set)
+        #[arg(long, default_value = "10s", value_parser = parse_interval)]
+        interval: std::time::Duration,
     },
     /// Send a message to a channel
     Send {
         /// Channel ID (e.g., C1RCG46LS)
         channel: String,
-        /// Message text
-        text: String,
+        #[command(flatten)]
+        body: BodyArgs,
         /// Reply in thread (parent message timestamp)
         #[arg(long)]
         thread_ts: Option<String>,
+        /// Schedule instead of sending now (e.g. 'tomorrow 9:00', '17:30', '+2h', ISO timestamp)
+        #[arg(long)]
+        at: Option<String>,
+        /// Upload text over 4000 characters as a snippet instead of splitting it into a thread
+        #[arg(long)]
+        snippet: bool,
+        /// Also post the thread reply to the channel
+        #[arg(long, requires = "thread_ts")]
+        reply_broadcast: bool,
+        /// Unfurl links in the text (true/false; Slack's default otherwise)
+        #[arg(long)]
+        unfurl_links: Option<bool>,
+        /// Unfurl media in the text (true/false; Slack's default otherwise)
+        #[arg(long)]
+        unfurl_media: Option<bool>,
+        /// Turn plain @name and #channel into mentions and links
+        #[arg(long)]
+        link_names: bool,
+        /// Format the text as mrkdwn (false sends it literally)
+        #[arg(long)]
+        mrkdwn: Option<bool>,
+    },
+    /// Send a message only one channel member can see
+    SendEphemeral {
+        /// Channel ID (e.g., C1RCG46LS)
+        channel: String,
+        /// User ID or @name (must be a channel member)
+        user: String,
+        #[command(flatten)]
+        body: BodyArgs,
+        /// Show in a thread (parent message timestamp)
+        #[arg(long)]
+        thread_ts: Option<String>,
+    },
+    /// Post a message for a key, or update the one already posted for it (e.g. one per CI build)
+    Upsert {
+        /// Channel ID (e.g., C1RCG46LS)
+        channel: String,
+        #[command(flatten)]
+        body: BodyArgs,
+        /// External ID the message belongs to (e.g. a build ID)
+        #[arg(long)]
+        key: String,
+        /// On updates, also add the new text as a reply in the message's thread
+        #[arg(long)]
+        thread_append: bool,
+    },
+    /// Post stdin as a live-updating message (e.g. `make deploy 2>&1 | slackline messages stream C1`)
+    Stream {
+        /// Channel ID (e.g., C1RCG46LS)
+        channel: String,
+        /// Heading shown above the output
+        #[arg(long)]
+        title: Option<String>,
+        /// Number of trailing lines to show
+        #[arg(long, default_value = "20")]
+        lines: usize,
+        /// Minimum time between message updates (e.g. 3s, 1m)
+        #[arg(long, default_value = "3s", value_parser = parse_interval)]
+        interval: std::time::Duration,
+        /// When done, upload the complete output as a file in the message's thread
+        #[arg(long)]
+        upload: bool,
+    },
+    /// Manage scheduled messages
+    Scheduled {
+        #[command(subcommand)]
+        command: ScheduledCommands,
+    },
+    /// Edit a message you sent
+    Edit {
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
+        channel: String,
+        /// Message timestamp (e.g., 1769415774.159039)
+        ts: String,
+        #[command(flatten)]
+        body: BodyArgs,
+    },
+    /// Delete a message
+    Delete {
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
+        channel: String,
+        /// Message timestamp (e.g., 1769415774.159039)
+        ts: String,
+        /// Ask for confirmation before deleting (interactive terminals only)
+        #[arg(long)]
+        confirm: bool,
+    },
+    /// Bulk-delete messages and thread replies in a channel (shows a count and sample, then asks)
+    Purge {
+        /// Channel name or ID
+        #[arg(long, short)]
+        channel: String,
+        /// Only your own messages (at least one of --from-me, --before, --match is required)
+        #[arg(long)]
+        from_me: bool,
+        /// Only messages older than this (e.g. 30d, 2024-01-15, 'last friday')
+        #[arg(long)]
+        before: Option<String>,
+        /// Only messages whose text matches this regex
+        #[arg(long = "match")]
+        pattern: Option<String>,
+        /// Delete without asking
+        #[arg(long, short)]
+        yes: bool,
+        /// Show what would be deleted, without deleting (allowed in readonly mode)
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Add an emoji reaction to a message
     React {
-        /// Channel ID (e.g., C1RCG46LS)
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
         channel: String,
         /// Message timestamp (e.g., 1769415774.159039)
         ts: String,
@@ -229,7 +659,7 @@ enum MessageCommands {
     },
     /// Remove an emoji reaction from a message
     Unreact {
-        /// Channel ID (e.g., C1RCG46LS)
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
         channel: String,
         /// Message timestamp (e.g., 1769415774.159039)
         ts: String,
@@ -238,20 +668,38 @@ enum MessageCommands {
     },
     /// Pin a message to a channel
     Pin {
-        /// Channel ID (e.g., C1RCG46LS)
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
         channel: String,
         /// Message timestamp (e.g., 1769415774.159039)
         ts: String,
     },
     /// Unpin a message from a channel
     Unpin {
-        /// Channel ID (e.g., C1RCG46LS)
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
         channel: String,
         /// Message timestamp (e.g., 1769415774.159039)
         ts: String,
     },
 }
 
+#[derive(Subcommand)]
+enum ScheduledCommands {
+    /// List your scheduled messages (returns id, channel, post_at, text)
+    List {
+        /// Only messages scheduled in this channel
+        #[arg(long, short)]
+        channel: Option<String>,
+    },
+    /// Delete a scheduled message
+    Delete {
+        /// Scheduled message ID (e.g., Q1298393284)
+        id: String,
+        /// Channel of the message (looked up when omitted)
+        #[arg(long, short)]
+        channel: Option<String>,
+    },
+}
+
 #[derive(Subcommand)]
 enum DmCommands {
     /// List DM conversations (returns channel ID for each)
@@ -276,13 +724,40 @@ enum DmCommands {
         /// Resolve user IDs to usernames and real names
         #[arg(long)]
         enrich: bool,
+        /// Fetch thread replies and show them under their parent
+        #[arg(long)]
+        with_replies: bool,
+        /// Return complete messages (blocks, attachments, files, edits, reactions...)
+        #[arg(long, conflicts_with_all = ["enrich", "with_replies"])]
+        full: bool,
     },
     /// Send a direct message to a user
     Send {
         /// User ID (e.g., U032LQBJTH8)
         user: String,
-        /// Message text
-        text: String,
+        /// Reply in thread (parent message timestamp)
+        #[arg(long)]
+        thread_ts: Option<String>,
+        #[command(flatten)]
+        body: BodyArgs,
+        /// Upload text over 4000 characters as a snippet instead of splitting it into a thread
+        #[arg(long)]
+        snippet: bool,
+        /// Also post the thread reply to the channel
+        #[arg(long, requires = "thread_ts")]
+        reply_broadcast: bool,
+        /// Unfurl links in the text (true/false; Slack's default otherwise)
+        #[arg(long)]
+        unfurl_links: Option<bool>,
+        /// Unfurl media in the text (true/false; Slack's default otherwise)
+        #[arg(long)]
+        unfurl_media: Option<bool>,
+        /// Turn plain @name and #channel into mentions and links
+        #[arg(long)]
+        link_names: bool,
+        /// Format the text as mrkdwn (false sends it literally)
+        #[arg(long)]
+        mrkdwn: Option<bool>,
     },
 }
 
@@ -353,6 +828,67 @@ enum MeCommands {
     },
     /// Clear your Slack status
     ClearStatus,
+    /// Mark all your channels and DMs as read
+    MarkAllRead {
+        /// Channels to leave unread (comma-separated names or IDs)
+        #[arg(long, value_delimiter = ',')]
+        except: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum PollCommands {
+    /// Post a poll and add one reaction per option
+    Create {
+        /// Channel ID (e.g., C1RCG46LS)
+        channel: String,
+        /// The question
+        question: String,
+        /// An option as 'Label=:emoji:', or just 'Label' for :one:, :two:, ... (repeatable)
+        #[arg(long = "option", required = true)]
+        options: Vec<String>,
+    },
+    /// Tally a poll's votes
+    Results {
+        /// Channel ID (e.g., C1RCG46LS), or a message permalink in place of both arguments
+        channel: String,
+        /// Poll message timestamp (e.g., 1769415774.159039)
+        ts: String,
+        /// Drop the votes of anyone who picked more than one option
+        #[arg(long)]
+        one_vote: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReminderCommands {
+    /// Add a reminder
+    Add {
+        /// What to be reminded about
+        text: String,
+        /// When (e.g. 'tomorrow 9am', 'friday 14:00', 'in 2 hours', or recurring 'every weekday at 9am')
+        #[arg(long)]
+        at: String,
+        /// Remind another user instead of yourself (ID or @name)
+        #[arg(long, short)]
+        user: Option<String>,
+    },
+    /// List your reminders
+    List {
+        /// Include completed reminders
+        #[arg(long)]
+        all: bool,
+    },
+    /// Mark a reminder as complete
+    Complete {
+        /// Reminder ID (e.g., Rm0123ABCD)
+        id: String,
+    },
+    /// Delete a reminder
+    Delete {
+        /// Reminder ID (e.g., Rm0123ABCD)
+        id: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -377,6 +913,15 @@ enum SearchCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum DbCommands {
+    /// Run a read-only SQL query (tables: channels, users, messages, reactions, sync_state)
+    Query {
+        /// SQL statement
+        sql: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum TokenCommands {
     /// Test token and show workspace/user info
@@ -405,6 +950,20 @@ fn parse_event_filter(s: &str) -> std::result::Result<EventFilter, String> {
     EventFilter::parse(s)
 }
 
+fn parse_conversation_type(
+    s: &str,
+) -> std::result::Result<slack_morphism::prelude::SlackConversationType, String> {
+    commands::channels::parse_conversation_type(s)
+}
+
+fn parse_channel_sort(s: &str) -> std::result::Result<ChannelSort, String> {
+    ChannelSort::parse(s)
+}
+
+fn parse_interval(s: &str) -> std::result::Result<std::time::Duration, String> {
+    slackline::timeparse::parse_interval(s)
+}
+
 fn resolve_config(token: Option<String>) -> anyhow::Result<Config> {
     Ok(match token {
         Some(token) => Config::with_token(token),
@@ -419,11 +978,40 @@ fn is_readonly() -> bool {
 }
 
 /// Names of write subcommands that should be hidden in readonly mode
-const WRITE_MESSAGE_CMDS: &[&str] = &["send", "react", "unreact", "pin", "unpin"];
+const WRITE_MESSAGE_CMDS: &[&str] = &[
+    "send",
+    "send-ephemeral",
+    "upsert",
+    "stream",
+    "forward",
+    "edit",
+    "delete",
+    "purge",
+    "react",
+    "unreact",
+    "pin",
+    "unpin",
+];
 const WRITE_DM_CMDS: &[&str] = &["send"];
 const WRITE_FILE_CMDS: &[&str] = &["upload"];
-const WRITE_ME_CMDS: &[&str] = &["set-status", "clear-status"];
-const WRITE_CHANNEL_CMDS: &[&str] = &["join", "leave"];
+const WRITE_ME_CMDS: &[&str] = &["set-status", "clear-status", "mark-all-read"];
+const WRITE_REMINDER_CMDS: &[&str] = &["add", "complete", "delete"];
+const WRITE_POLL_CMDS: &[&str] = &["create"];
+const WRITE_SCHEDULED_CMDS: &[&str] = &["delete"];
+const WRITE_BOOKMARK_CMDS: &[&str] = &["add", "edit", "remove", "apply"];
+const WRITE_CHANNEL_CMDS: &[&str] = &[
+    "join",
+    "leave",
+    "create",
+    "archive",
+    "unarchive",
+    "rename",
+    "set-topic",
+    "set-purpose",
+    "invite",
+    "kick",
+    "mark",
+];
 
 fn hide_write_subcommands(mut cmd: clap::Command) -> clap::Command {
     for name in WRITE_MESSAGE_CMDS {
@@ -438,9 +1026,25 @@ fn hide_write_subcommands(mut cmd: clap::Command) -> clap::Command {
     for name in WRITE_ME_CMDS {
         cmd = cmd.mut_subcommand("me", |m| m.mut_subcommand(name, |s| s.hide(true)));
     }
+    for name in WRITE_REMINDER_CMDS {
+        cmd = cmd.mut_subcommand("reminders", |m| m.mut_subcommand(name, |s| s.hide(true)));
+    }
+    for name in WRITE_POLL_CMDS {
+        cmd = cmd.mut_subcommand("polls", |m| m.mut_subcommand(name, |s| s.hide(true)));
+    }
     for name in WRITE_CHANNEL_CMDS {
         cmd = cmd.mut_subcommand("channels", |m| m.mut_subcommand(name, |s| s.hide(true)));
     }
+    for name in WRITE_SCHEDULED_CMDS {
+        cmd = cmd.mut_subcommand("messages", |m| {
+            m.mut_subcommand("scheduled", |b| b.mut_subcommand(name, |s| s.hide(true)))
+        });
+    }
+    for name in WRITE_BOOKMARK_CMDS {
+        cmd = cmd.mut_subcommand("channels", |m| {
+            m.mut_subcommand("bookmarks", |b| b.mut_subcommand(name, |s| s.hide(true)))
+        });
+    }
     cmd
 }
 
@@ -449,6 +1053,16 @@ fn is_write_command(cmd: &Commands) -> bool {
         cmd,
         Commands::Messages {
             command: MessageCommands::Send { .. }
+                | MessageCommands::SendEphemeral { .. }
+                | MessageCommands::Upsert { .. }
+                | MessageCommands::Stream { .. }
+                | MessageCommands::Forward { .. }
+                | MessageCommands::Scheduled {
+                    command: ScheduledCommands::Delete { .. }
+                }
+                | MessageCommands::Edit { .. }
+                | MessageCommands::Delete { .. }
+                | MessageCommands::Purge { dry_run: false, .. }
                 | MessageCommands::React { .. }
                 | MessageCommands::Unreact { .. }
                 | MessageCommands::Pin { .. }
@@ -458,13 +1072,107 @@ fn is_write_command(cmd: &Commands) -> bool {
         } | Commands::Files {
             command: FileCommands::Upload { .. }
         } | Commands::Me {
-            command: MeCommands::SetStatus { .. } | MeCommands::ClearStatus
+            command: MeCommands::SetStatus { .. }
+                | MeCommands::ClearStatus
+                | MeCommands::MarkAllRead { .. }
+        } | Commands::Reminders {
+            command: ReminderCommands::Add { .. }
+                | ReminderCommands::Complete { .. }
+                | ReminderCommands::Delete { .. }
+        } | Commands::Polls {
+            command: PollCommands::Create { .. }
         } | Commands::Channels {
-            command: ChannelCommands::Join { .. } | ChannelCommands::Leave { .. }
+            command: ChannelCommands::Join { .. }
+                | ChannelCommands::Leave { .. }
+                | ChannelCommands::Create { .. }
+                | ChannelCommands::Archive { .. }
+                | ChannelCommands::Unarchive { .. }
+                | ChannelCommands::Rename { .. }
+                | ChannelCommands::SetTopic { .. }
+                | ChannelCommands::SetPurpose { .. }
+                | ChannelCommands::Invite { .. }
+                | ChannelCommands::Kick { .. }
+                | ChannelCommands::Mark { .. }
+                | ChannelCommands::Bookmarks {
+                    command: BookmarkCommands::Add { .. }
+                        | BookmarkCommands::Edit { .. }
+                        | BookmarkCommands::Remove { .. }
+                        | BookmarkCommands::Apply { .. }
+                }
         }
     )
 }
 
+/// `messages` subcommands addressing a message as `<channel> <ts>`
+const MESSAGE_REF_CMDS: &[&str] = &[
+    "replies",
+    "get",
+    "permalink",
+    "reactions",
+    "forward",
+    "await",
+    "edit",
+    "delete",
+    "react",
+    "unreact",
+    "pin",
+    "unpin",
+];
+const POLL_REF_CMDS: &[&str] = &["results"];
+
+/// Global flags that take a value, so their value isn't mistaken for the subcommand.
+const GLOBAL_VALUE_FLAGS: &[&str] = &["--token", "--db"];
+
+/// Index of the first positional argument at or after `from`, skipping global flags.
+fn next_positional(args: &[String], from: usize) -> Option<usize> {
+    let mut i = from;
+    while i < args.len() {
+        let arg = args[i].as_str();
+        if GLOBAL_VALUE_FLAGS.contains(&arg) {
+            i += 2;
+        } else if arg.starts_with('-') {
+            i += 1;
+        } else {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Replace a message permalink passed to a `messages` (or `polls`) subcommand with its
+/// `<channel> <ts>` (the thread's parent ts for `replies`), so every command taking both
+/// accepts a link.
+fn expand_permalinks(mut args: Vec<String>) -> Vec<String> {
+    let Some(pos) = next_positional(&args, 1) else {
+        return args;
+    };
+    let ref_cmds = match args[pos].as_str() {
+        "messages" => MESSAGE_REF_CMDS,
+        "polls" => POLL_REF_CMDS,
+        _ => return args,
+    };
+    let Some(sub) =
+        next_positional(&args, pos + 1).filter(|&i| ref_cmds.contains(&args[i].as_str()))
+    else {
+        return args;
+    };
+    let thread = args[sub] == "replies";
+
+    // Only the first positional can be a permalink: skip flags and their values
+    for i in sub + 1..args.len() {
+        if let Some(link) = parse_permalink(&args[i]) {
+            let ts = if thread { link.thread() } else { &link.ts };
+            let ts = ts.to_string();
+            args.splice(i..=i, [link.channel, ts]);
+            break;
+        }
+        if !args[i].starts_with('-') && !args[i - 1].starts_with('-') {
+            break;
+        }
+    }
+    args
+}
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let readonly = is_readonly();
@@ -475,7 +1183,7 @@ async fn main() -> anyhow::Result<()> {
         cmd = hide_write_subcommands(cmd);
     }
 
-    let matches = cmd.get_matches();
+    let matches = cmd.get_matches_from(expand_permalinks(std::env::args().collect()));
     let cli = Cli::from_arg_matches(&matches)?;
     let output = Output::new(cli.json, cli.quiet, cli.pretty);
 
@@ -525,6 +1233,65 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    // Handle local mirror reads (no auth required)
+    let db_path = cli
+        .db
+        .as_deref()
+        .map(PathBuf::from)
+        .unwrap_or_else(slackline::mirror::default_path);
+    let offline_result = match &cmd {
+        Commands::Db {
+            command: DbCommands::Query { sql },
+        } => Some(commands::db::query(&output, &db_path, sql)),
+        Commands::Channels {
+            command:
+                ChannelCommands::History {
+                    channel,
+                    limit,
+                    after,
+                    before,
+                    enrich,
+                    offline: true,
+                    ..
+                },
+        } => Some(commands::db::history(
+            &output,
+            &db_path,
+            channel,
+            *limit,
+            after.as_deref(),
+            before.as_deref(),
+            *enrich,
+        )),
+        Commands::Messages {
+            command:
+                MessageCommands::Replies {
+                    channel,
+                    thread_ts,
+                    limit,
+                    offline: true,
+                    ..
+                },
+        } => Some(commands::db::replies(
+            &output, &db_path, channel, thread_ts, *limit,
+        )),
+        Commands::Users {
+            command:
+                UserCommands::Search {
+                    query,
+                    offline: true,
+                },
+        } => Some(commands::db::search_users(&output, &db_path, query)),
+        _ => None,
+    };
+    if let Some(result) = offline_result {
+        if let Err(e) = result {
+            output.error_structured(&e);
+            std::process::exit(e.exit_code());
+        }
+        return Ok(());
+    }
+
     // Handle watch command (needs config but not the usual client)
     if let Commands::Watch {
         events,
@@ -557,17 +1324,51 @@ async fn main() -> anyhow::Result<()> {
 
     let client = SlackClient::new(&config)?;
 
-    let result = match cmd {
+    let result = run(cmd, &client, &output, &config, &db_path).await;
+
+    if let Err(e) = result {
+        output.error_structured(&e);
+        std::process::exit(e.exit_code());
+    }
+
+    Ok(())
+}
+
+/// Run a command that needs an authenticated client.
+async fn run(
+    cmd: Commands,
+    client: &SlackClient,
+    output: &Output,
+    config: &Config,
+    db_path: &Path,
+) -> slackline::error::Result<()> {
+    match cmd {
         Commands::Token { command } => match command {
-            TokenCommands::Test => commands::token::test(&client, &output).await,
+            TokenCommands::Test => commands::token::test(client, output).await,
             TokenCommands::Create { .. } | TokenCommands::Manifest { .. } => unreachable!(),
         },
         Commands::Channels { command } => match command {
-            ChannelCommands::List { limit } => {
-                commands::channels::list(&client, &output, limit).await
+            ChannelCommands::List {
+                limit,
+                types,
+                include_archived,
+                name,
+                min_members,
+                created_after,
+                sort,
+            } => {
+                let filters = ListFilters {
+                    types,
+                    include_archived,
+                    name,
+                    min_members,
+                    created_after,
+                    sort,
+                };
+                commands::channels::list(client, output, limit, &filters).await
             }
             ChannelCommands::Info { channel } => {
-                commands::channels::info(&client, &output, &channel).await
+                commands::channels::info(client, output, &channel).await
             }
             ChannelCommands::History {
                 channel,
@@ -575,39 +1376,152 @@ async fn main() -> anyhow::Result<()> {
                 after,
                 before,
                 enrich,
+                with_replies,
+                offline: _,
+                follow,
+                interval,
+                full,
             } => {
-                commands::channels::history(
-                    &client,
-                    &output,
-                    &channel,
+                let opts = HistoryOptions {
                     limit,
-                    after.as_deref(),
-                    before.as_deref(),
+                    after,
+                    before,
+                    enrich,
+                    with_replies,
+                    full,
+                };
+                if follow {
+                    commands::follow::follow(client, output, &channel, &opts, interval).await
+                } else {
+                    commands::channels::history(client, output, &channel, &opts).await
+                }
+            }
+            ChannelCommands::Members {
+                channel,
+                limit,
+                enrich,
+                presence,
+                exclude_bots,
+                exclude_deleted,
+            } => {
+                let opts = MemberOptions {
                     enrich,
+                    presence,
+                    exclude_bots,
+                    exclude_deleted,
+                };
+                commands::channels::members(client, output, &channel, limit, &opts).await
+            }
+            ChannelCommands::Stats {
+                channel,
+                after,
+                before,
+                top,
+            } => {
+                commands::stats::channel_stats(
+                    client,
+                    output,
+                    &channel,
+                    Some(&after),
+                    before.as_deref(),
+                    top,
                 )
                 .await
             }
-            ChannelCommands::Members { channel, limit } => {
-                commands::channels::members(&client, &output, &channel, limit).await
-            }
+            ChannelCommands::Bookmarks { command } => match command {
+                BookmarkCommands::List { channels } => {
+                    commands::bookmarks::list(client, output, &channels).await
+                }
+                BookmarkCommands::Add {
+                    channel,
+                    title,
+                    link,
+                    emoji,
+                    kind,
+                } => {
+                    commands::bookmarks::add(
+                        client,
+                        output,
+                        &channel,
+                        &title,
+                        &link,
+                        emoji.as_deref(),
+                        &kind,
+                    )
+                    .await
+                }
+                BookmarkCommands::Edit {
+                    channel,
+                    bookmark,
+                    title,
+                    link,
+                    emoji,
+                } => {
+                    commands::bookmarks::edit(
+                        client,
+                        output,
+                        &channel,
+                        &bookmark,
+                        title.as_deref(),
+                        link.as_deref(),
+                        emoji.as_deref(),
+                    )
+                    .await
+                }
+                BookmarkCommands::Remove { channel, bookmark } => {
+                    commands::bookmarks::remove(client, output, &channel, &bookmark).await
+                }
+                BookmarkCommands::Apply {
+                    channel,
+                    from_file,
+                    prune,
+                } => commands::bookmarks::apply(client, output, &channel, &from_file, prune).await,
+            },
             ChannelCommands::Pins { channel } => {
-                commands::channels::pins(&client, &output, &channel).await
+                commands::channels::pins(client, output, &channel).await
             }
             ChannelCommands::Join { channel } => {
-                commands::channels::join(&client, &output, &channel).await
+                commands::channels::join(client, output, &channel).await
             }
             ChannelCommands::Leave { channel } => {
-                commands::channels::leave(&client, &output, &channel).await
+                commands::channels::leave(client, output, &channel).await
+            }
+            ChannelCommands::Create { name, private } => {
+                commands::channels::create(client, output, &name, private).await
+            }
+            ChannelCommands::Archive { channel } => {
+                commands::channels::archive(client, output, &channel).await
+            }
+            ChannelCommands::Unarchive { channel } => {
+                commands::channels::unarchive(client, output, &channel).await
+            }
+            ChannelCommands::Rename { channel, name } => {
+                commands::channels::rename(client, output, &channel, &name).await
+            }
+            ChannelCommands::SetTopic { channel, topic } => {
+                commands::channels::set_topic(client, output, &channel, &topic).await
+            }
+            ChannelCommands::SetPurpose { channel, purpose } => {
+                commands::channels::set_purpose(client, output, &channel, &purpose).await
+            }
+            ChannelCommands::Invite { channel, users } => {
+                commands::channels::invite(client, output, &channel, &users).await
+            }
+            ChannelCommands::Kick { channel, user } => {
+                commands::channels::kick(client, output, &channel, &user).await
+            }
+            ChannelCommands::Mark { channel, ts } => {
+                commands::channels::mark(client, output, &channel, ts.as_deref()).await
             }
         },
         Commands::Users { command } => match command {
-            UserCommands::List { limit } => commands::users::list(&client, &output, limit).await,
-            UserCommands::Info { user } => commands::users::info(&client, &output, &user).await,
-            UserCommands::Search { query } => {
-                commands::users::search(&client, &output, &query).await
+            UserCommands::List { limit } => commands::users::list(client, output, limit).await,
+            UserCommands::Info { user } => commands::users::info(client, output, &user).await,
+            UserCommands::Search { query, .. } => {
+                commands::users::search(client, output, &query).await
             }
             UserCommands::Presence { user } => {
-                commands::users::presence(&client, &output, &user).await
+                commands::users::presence(client, output, &user).await
             }
         },
         Commands::Messages { command } => match command {
@@ -615,57 +1529,230 @@ async fn main() -> anyhow::Result<()> {
                 channel,
                 thread_ts,
                 limit,
-            } => commands::messages::replies(&client, &output, &channel, &thread_ts, limit).await,
+                offline: _,
+                full,
+            } => {
+                commands::messages::replies(client, output, &channel, &thread_ts, limit, full).await
+            }
+            MessageCommands::Get { channel, ts } => {
+                commands::messages::get(client, output, &channel, &ts).await
+            }
             MessageCommands::Permalink {
                 channel,
                 message_ts,
-            } => commands::messages::permalink(&client, &output, &channel, &message_ts).await,
+            } => commands::messages::permalink(client, output, &channel, &message_ts).await,
             MessageCommands::Reactions { channel, ts } => {
-                commands::messages::reactions(&client, &output, &channel, &ts).await
+                commands::messages::reactions(client, output, &channel, &ts).await
+            }
+            MessageCommands::Forward {
+                channel,
+                ts,
+                to,
+                comment,
+                quote,
+            } => {
+                commands::messages::forward(
+                    client,
+                    output,
+                    &channel,
+                    &ts,
+                    &to,
+                    comment.as_deref(),
+                    quote,
+                )
+                .await
+            }
+            MessageCommands::Await {
+                channel,
+                ts,
+                reaction,
+                reject,
+                from,
+                reply_matches,
+                timeout,
+                interval,
+            } => {
+                let opts = AwaitOptions {
+                    reaction,
+                    reject: Some(reject),
+                    from,
+                    reply_matches,
+                    timeout,
+                    interval,
+                };
+                commands::approval::await_approval(
+                    client,
+                    output,
+                    &channel,
+                    &ts,
+                    config.app_token.as_deref(),
+                    &opts,
+                )
+                .await
             }
             MessageCommands::Send {
                 channel,
-                text,
+                body,
                 thread_ts,
+                at,
+                snippet,
+                reply_broadcast,
+                unfurl_links,
+                unfurl_media,
+                link_names,
+                mrkdwn,
             } => {
-                commands::messages::send(&client, &output, &channel, &text, thread_ts.as_deref())
-                    .await
+                let body = body.into_body()?;
+                let opts = PostOptions {
+                    reply_broadcast,
+                    unfurl_links,
+                    unfurl_media,
+                    link_names,
+                    mrkdwn,
+                    snippet,
+                };
+                commands::messages::send(
+                    client,
+                    output,
+                    &channel,
+                    &body,
+                    thread_ts.as_deref(),
+                    at.as_deref(),
+                    &opts,
+                )
+                .await
+            }
+            MessageCommands::SendEphemeral {
+                channel,
+                user,
+                body,
+                thread_ts,
+            } => {
+                let body = body.into_body()?;
+                commands::messages::send_ephemeral(
+                    client,
+                    output,
+                    &channel,
+                    &user,
+                    &body,
+                    thread_ts.as_deref(),
+                )
+                .await
+            }
+            MessageCommands::Upsert {
+                channel,
+                body,
+                key,
+                thread_append,
+            } => {
+                let body = body.into_body()?;
+                commands::upsert::upsert(client, output, &channel, &key, &body, thread_append).await
+            }
+            MessageCommands::Stream {
+                channel,
+                title,
+                lines,
+                interval,
+                upload,
+            } => {
+                let opts = StreamOptions {
+                    title,
+                    lines,
+                    interval,
+                    upload,
+                };
+                commands::stream::stream(client, output, &channel, &opts).await
+            }
+            MessageCommands::Scheduled { command } => match command {
+                ScheduledCommands::List { channel } => {
+                    commands::messages::scheduled_list(client, output, channel.as_deref()).await
+                }
+                ScheduledCommands::Delete { id, channel } => {
+                    commands::messages::scheduled_delete(client, output, &id, channel.as_deref())
+                        .await
+                }
+            },
+            MessageCommands::Edit { channel, ts, body } => {
+                let body = body.into_body()?;
+                commands::messages::edit(client, output, &channel, &ts, &body).await
+            }
+            MessageCommands::Delete {
+                channel,
+                ts,
+                confirm,
+            } => commands::messages::delete(client, output, &channel, &ts, confirm).await,
+            MessageCommands::Purge {
+                channel,
+                from_me,
+                before,
+                pattern,
+                yes,
+                dry_run,
+            } => {
+                let opts = PurgeOptions {
+                    from_me,
+                    before,
+                    pattern,
+                    yes,
+                    dry_run,
+                };
+                commands::messages::purge(client, output, &channel, &opts).await
             }
             MessageCommands::React { channel, ts, emoji } => {
-                commands::messages::react(&client, &output, &channel, &ts, &emoji).await
+                commands::messages::react(client, output, &channel, &ts, &emoji).await
             }
             MessageCommands::Unreact { channel, ts, emoji } => {
-                commands::messages::unreact(&client, &output, &channel, &ts, &emoji).await
+                commands::messages::unreact(client, output, &channel, &ts, &emoji).await
             }
             MessageCommands::Pin { channel, ts } => {
-                commands::messages::pin(&client, &output, &channel, &ts).await
+                commands::messages::pin(client, output, &channel, &ts).await
             }
             MessageCommands::Unpin { channel, ts } => {
-                commands::messages::unpin(&client, &output, &channel, &ts).await
+                commands::messages::unpin(client, output, &channel, &ts).await
             }
         },
         Commands::Dms { command } => match command {
-            DmCommands::List { limit } => commands::dms::list(&client, &output, limit).await,
+            DmCommands::List { limit } => commands::dms::list(client, output, limit).await,
             DmCommands::History {
                 dm_channel,
                 limit,
                 after,
                 before,
                 enrich,
+                with_replies,
+                full,
             } => {
-                commands::dms::history(
-                    &client,
-                    &output,
-                    &dm_channel,
+                let opts = HistoryOptions {
                     limit,
-                    after.as_deref(),
-                    before.as_deref(),
+                    after,
+                    before,
                     enrich,
-                )
-                .await
+                    with_replies,
+                    full,
+                };
+                commands::dms::history(client, output, &dm_channel, &opts).await
             }
-            DmCommands::Send { user, text } => {
-                commands::dms::send(&client, &output, &user, &text).await
+            DmCommands::Send {
+                user,
+                thread_ts,
+                body,
+                snippet,
+                reply_broadcast,
+                unfurl_links,
+                unfurl_media,
+                link_names,
+                mrkdwn,
+            } => {
+                let body = body.into_body()?;
+                let opts = PostOptions {
+                    reply_broadcast,
+                    unfurl_links,
+                    unfurl_media,
+                    link_names,
+                    mrkdwn,
+                    snippet,
+                };
+                commands::dms::send(client, output, &user, &body, thread_ts.as_deref(), &opts).await
             }
         },
         Commands::Files { command } => match command {
@@ -674,12 +1761,12 @@ async fn main() -> anyhow::Result<()> {
                 user,
                 limit,
             } => {
-                commands::files::list(&client, &output, channel.as_deref(), user.as_deref(), limit)
+                commands::files::list(client, output, channel.as_deref(), user.as_deref(), limit)
                     .await
             }
-            FileCommands::Info { file } => commands::files::info(&client, &output, &file).await,
+            FileCommands::Info { file } => commands::files::info(client, output, &file).await,
             FileCommands::Download { file, output: out } => {
-                commands::files::download(&client, &file, out.as_deref()).await
+                commands::files::download(client, &file, out.as_deref()).await
             }
             FileCommands::Upload {
                 path,
@@ -688,8 +1775,8 @@ async fn main() -> anyhow::Result<()> {
                 comment,
             } => {
                 commands::files::upload(
-                    &client,
-                    &output,
+                    client,
+                    output,
                     &path,
                     channel.as_deref(),
                     thread_ts.as_deref(),
@@ -700,25 +1787,181 @@ async fn main() -> anyhow::Result<()> {
         },
         Commands::Me { command } => match command {
             MeCommands::Channels { limit, dms, unread } => {
-                commands::me::channels(&client, &output, limit, dms, unread).await
+                commands::me::channels(client, output, limit, dms, unread).await
             }
             MeCommands::SetStatus { text, emoji } => {
-                commands::me::set_status(&client, &output, &text, emoji.as_deref()).await
+                commands::me::set_status(client, output, &text, emoji.as_deref()).await
+            }
+            MeCommands::ClearStatus => commands::me::clear_status(client, output).await,
+            MeCommands::MarkAllRead { except } => {
+                commands::me::mark_all_read(client, output, &except).await
+            }
+        },
+        Commands::Polls { command } => match command {
+            PollCommands::Create {
+                channel,
+                question,
+                options,
+            } => commands::polls::create(client, output, &channel, &question, &options).await,
+            PollCommands::Results {
+                channel,
+                ts,
+                one_vote,
+            } => commands::polls::results(client, output, &channel, &ts, one_vote).await,
+        },
+        Commands::Reminders { command } => match command {
+            ReminderCommands::Add { text, at, user } => {
+                commands::reminders::add(client, output, &text, &at, user.as_deref()).await
+            }
+            ReminderCommands::List { all } => commands::reminders::list(client, output, all).await,
+            ReminderCommands::Complete { id } => {
+                commands::reminders::complete(client, output, &id).await
+            }
+            ReminderCommands::Delete { id } => {
+                commands::reminders::delete(client, output, &id).await
             }
-            MeCommands::ClearStatus => commands::me::clear_status(&client, &output).await,
         },
         Commands::Search { command } => match command {
             SearchCommands::Messages { query, limit, page } => {
-                commands::search::messages(&client, &output, &query, limit, page).await
+                commands::search::messages(client, output, &query, limit, page).await
             }
         },
-        Commands::Watch { .. } | Commands::Completions { .. } => unreachable!("handled above"),
-    };
+        Commands::Export {
+            channels,
+            out,
+            after,
+            before,
+            files,
+        } => {
+            commands::export::export(
+                client,
+                output,
+                &channels,
+                &out,
+                after.as_deref(),
+                before.as_deref(),
+                files,
+            )
+            .await
+        }
+        Commands::Sync { channels } => {
+            commands::sync::sync(client, output, &channels, db_path).await
+        }
+        Commands::Db { .. } | Commands::Watch { .. } | Commands::Completions { .. } => {
+            unreachable!("handled above")
+        }
+    }
+}
 
-    if let Err(e) = result {
-        output.error_structured(&e);
-        std::process::exit(e.exit_code());
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINK: &str = "https://acme.slack.com/archives/C1RCG46LS/p1769415774159039";
+    const REPLY_LINK: &str = "https://acme.slack.com/archives/C1RCG46LS/p1769415774159039\
+                              ?thread_ts=1769415700.000100&cid=C1RCG46LS";
+
+    fn expand(args: &[&str]) -> Vec<String> {
+        let args = std::iter::once("slackline")
+            .chain(args.iter().copied())
+            .map(String::from)
+            .collect();
+        expand_permalinks(args)[1..].to_vec()
     }
 
-    Ok(())
+    #[test]
+    fn test_expand_message_link() {
+        assert_eq!(
+            expand(&["messages", "get", LINK]),
+            ["messages", "get", "C1RCG46LS", "1769415774.159039"]
+        );
+        assert_eq!(
+            expand(&["polls", "results", LINK]),
+            ["polls", "results", "C1RCG46LS", "1769415774.159039"]
+        );
+    }
+
+    #[test]
+    fn test_expand_thread_link() {
+        // `replies` wants the thread's parent, everything else the message itself
+        assert_eq!(
+            expand(&["messages", "replies", REPLY_LINK]),
+            ["messages", "replies", "C1RCG46LS", "1769415700.000100"]
+        );
+        assert_eq!(
+            expand(&["messages", "get", REPLY_LINK]),
+            ["messages", "get", "C1RCG46LS", "1769415774.159039"]
+        );
+    }
+
+    #[test]
+    fn test_expand_after_flags() {
+        assert_eq!(
+            expand(&["--db", "/tmp/m.db", "--json", "messages", "get", LINK]),
+            [
+                "--db",
+                "/tmp/m.db",
+                "--json",
+                "messages",
+                "get",
+                "C1RCG46LS",
+                "1769415774.159039"
+            ]
+        );
+        assert_eq!(
+            expand(&["messages", "--quiet", "react", "--json", LINK, "eyes"]),
+            [
+                "messages",
+                "--quiet",
+                "react",
+                "--json",
+                "C1RCG46LS",
+                "1769415774.159039",
+                "eyes"
+            ]
+        );
+        assert_eq!(
+            expand(&["messages", "await", "--timeout", "30m", LINK]),
+            [
+                "messages",
+                "await",
+                "--timeout",
+                "30m",
+                "C1RCG46LS",
+                "1769415774.159039"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_only_at_subcommand_position() {
+        // A flag value or channel named `messages` isn't the `messages` command
+        let args = ["--db", "messages", "channels", "history", LINK];
+        assert_eq!(expand(&args), args);
+        let args = ["channels", "history", "messages", "get", LINK];
+        assert_eq!(expand(&args), args);
+        let args = ["messages", "send", "general", LINK];
+        assert_eq!(expand(&args), args);
+    }
+
+    #[test]
+    fn test_expand_leaves_other_args() {
+        let args = ["messages", "get", "C1RCG46LS", "1769415774.159039"];
+        assert_eq!(expand(&args), args);
+        let args = [
+            "messages",
+            "get",
+            "https://example.com/archives/C1/p1769415774159039",
+        ];
+        assert_eq!(expand(&args), args);
+        // Only the first positional is considered
+        let args = [
+            "messages",
+            "forward",
+            "C1RCG46LS",
+            "1769415774.159039",
+            LINK,
+        ];
+        assert_eq!(expand(&args), args);
+    }
 }
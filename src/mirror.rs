@@ -0,0 +1,455 @@
+use crate::commands::channels::MessageInfo;
+use crate::commands::messages::ReplyInfo;
+use crate::commands::users::{UserInfo, user_from_slack};
+use crate::error::{Result, SlackCliError};
+use crate::timeparse::parse_slack_ts;
+use rusqlite::{Connection, OpenFlags, OptionalExtension, params};
+use slack_morphism::prelude::*;
+use std::path::{Path, PathBuf};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS channels (
+    id TEXT PRIMARY KEY,
+    name TEXT,
+    topic TEXT,
+    purpose TEXT,
+    is_private INTEGER NOT NULL,
+    is_archived INTEGER NOT NULL,
+    raw TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS users (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    real_name TEXT,
+    display_name TEXT,
+    email TEXT,
+    title TEXT,
+    is_admin INTEGER NOT NULL,
+    is_bot INTEGER NOT NULL,
+    deleted INTEGER NOT NULL,
+    tz TEXT,
+    raw TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS messages (
+    channel TEXT NOT NULL,
+    ts TEXT NOT NULL,
+    thread_ts TEXT,
+    user TEXT,
+    text TEXT NOT NULL,
+    subtype TEXT,
+    reply_count INTEGER,
+    latest_reply TEXT,
+    raw TEXT NOT NULL,
+    PRIMARY KEY (channel, ts)
+);
+CREATE INDEX IF NOT EXISTS messages_thread ON messages (channel, thread_ts);
+CREATE TABLE IF NOT EXISTS reactions (
+    channel TEXT NOT NULL,
+    ts TEXT NOT NULL,
+    name TEXT NOT NULL,
+    user TEXT NOT NULL,
+    PRIMARY KEY (channel, ts, name, user)
+);
+CREATE TABLE IF NOT EXISTS sync_state (
+    channel TEXT PRIMARY KEY,
+    cursor_ts TEXT,
+    synced_at TEXT NOT NULL
+);
+";
+
+/// Default mirror location: `$XDG_DATA_HOME/slackline/mirror.db`, falling back to
+/// `~/.local/share/slackline/mirror.db`.
+pub fn default_path() -> PathBuf {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".local/share")))
+        .unwrap_or_else(|| PathBuf::from("."));
+    base.join("slackline").join("mirror.db")
+}
+
+/// Local SQLite mirror of channels, users, messages and reactions.
+pub struct Mirror {
+    conn: Connection,
+}
+
+impl Mirror {
+    /// Open (creating if needed) the mirror for writing.
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(path)?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Open an existing mirror without write access.
+    pub fn open_readonly(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Err(SlackCliError::Config(format!(
+                "No local mirror at {}. Run `slackline sync` first.",
+                path.display()
+            )));
+        }
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        Ok(Self { conn })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    pub fn begin(&mut self) -> Result<rusqlite::Transaction<'_>> {
+        Ok(self.conn.transaction()?)
+    }
+
+    pub fn upsert_channel(conn: &Connection, channel: &SlackChannelInfo) -> Result<()> {
+        conn.execute(
+            "INSERT OR REPLACE INTO channels (id, name, topic, purpose, is_private, is_archived, raw)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                channel.id.0,
+                channel.name,
+                channel.topic.as_ref().map(|t| &t.value),
+                channel.purpose.as_ref().map(|p| &p.value),
+                channel.flags.is_private.unwrap_or(false),
+                channel.flags.is_archived.unwrap_or(false),
+                raw_json(channel)?,
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn upsert_user(conn: &Connection, user: &SlackUser) -> Result<()> {
+        let raw = raw_json(user)?;
+        let u = user_from_slack(user.clone());
+        conn.execute(
+            "INSERT OR REPLACE INTO users
+             (id, name, real_name, display_name, email, title, is_admin, is_bot, deleted, tz, raw)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                u.id,
+                u.name,
+                u.real_name,
+                u.display_name,
+                u.email,
+                u.title,
+                u.is_admin,
+                u.is_bot,
+                u.deleted,
+                u.tz,
+                raw,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Insert or update a message and replace its reactions.
+    pub fn upsert_message(
+        conn: &Connection,
+        channel: &str,
+        message: &SlackHistoryMessage,
+    ) -> Result<()> {
+        let subtype = message.subtype.as_ref().and_then(|s| {
+            serde_json::to_value(s)
+                .ok()
+                .and_then(|v| v.as_str().map(String::from))
+        });
+        conn.execute(
+            "INSERT OR REPLACE INTO messages
+             (channel, ts, thread_ts, user, text, subtype, reply_count, latest_reply, raw)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                channel,
+                message.origin.ts.0,
+                message.origin.thread_ts.as_ref().map(|t| &t.0),
+                message.sender.user.as_ref().map(|u| &u.0),
+                message.content.text.as_deref().unwrap_or_default(),
+                subtype,
+                message.parent.reply_count.map(|c| c as i64),
+                message.parent.latest_reply.as_ref().map(|t| &t.0),
+                raw_json(message)?,
+            ],
+        )?;
+
+        conn.execute(
+            "DELETE FROM reactions WHERE channel = ?1 AND ts = ?2",
+            params![channel, message.origin.ts.0],
+        )?;
+        for reaction in message.content.reactions.iter().flatten() {
+            for user in &reaction.users {
+                conn.execute(
+                    "INSERT OR IGNORE INTO reactions (channel, ts, name, user) VALUES (?1, ?2, ?3, ?4)",
+                    params![channel, message.origin.ts.0, reaction.name.0, user.0],
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Newest top-level ts mirrored for a channel.
+    pub fn cursor(&self, channel: &str) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT cursor_ts FROM sync_state WHERE channel = ?1",
+                params![channel],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten())
+    }
+
+    pub fn set_cursor(conn: &Connection, channel: &str, cursor_ts: Option<&str>) -> Result<()> {
+        conn.execute(
+            "INSERT OR REPLACE INTO sync_state (channel, cursor_ts, synced_at)
+             VALUES (?1, ?2, datetime('now'))",
+            params![channel, cursor_ts],
+        )?;
+        Ok(())
+    }
+
+    /// Latest reply ts recorded on a mirrored thread parent.
+    pub fn latest_reply(&self, channel: &str, ts: &str) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT latest_reply FROM messages WHERE channel = ?1 AND ts = ?2",
+                params![channel, ts],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten())
+    }
+
+    /// Resolve a channel name, `#name` or ID against the mirrored channels.
+    pub fn resolve_channel(&self, channel: &str) -> Result<String> {
+        let name = channel.strip_prefix('#').unwrap_or(channel);
+        self.conn
+            .query_row(
+                "SELECT id FROM channels WHERE id = ?1 OR name = ?1",
+                params![name],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or_else(|| SlackCliError::ChannelNotFound(channel.to_string()))
+    }
+
+    /// Top-level messages in a channel, newest first.
+    pub fn history(
+        &self,
+        channel: &str,
+        limit: u16,
+        oldest: Option<&str>,
+        latest: Option<&str>,
+    ) -> Result<Vec<MessageInfo>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT m.ts, m.user, m.text, m.thread_ts, m.reply_count, m.latest_reply, u.name, u.real_name
+             FROM messages m LEFT JOIN users u ON u.id = m.user
+             WHERE m.channel = ?1
+               AND (m.thread_ts IS NULL OR m.thread_ts = m.ts OR m.subtype = 'thread_broadcast')
+               AND (?2 IS NULL OR CAST(m.ts AS REAL) > CAST(?2 AS REAL))
+               AND (?3 IS NULL OR CAST(m.ts AS REAL) < CAST(?3 AS REAL))
+             ORDER BY CAST(m.ts AS REAL) DESC
+             LIMIT ?4",
+        )?;
+        let rows = stmt.query_map(params![channel, oldest, latest, limit], |row| {
+            let ts: String = row.get(0)?;
+            Ok(MessageInfo {
+                timestamp: parse_slack_ts(&ts).ok(),
+                ts,
+                user: row.get(1)?,
+                text: row.get(2)?,
+                thread_ts: row.get(3)?,
+                reply_count: row.get::<_, Option<i64>>(4)?.map(|c| c as u64),
+                latest_reply: row.get(5)?,
+                username: row.get(6)?,
+                real_name: row.get(7)?,
+                replies: None,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// A thread (parent first, then replies), oldest first.
+    pub fn replies(&self, channel: &str, thread_ts: &str, limit: u16) -> Result<Vec<ReplyInfo>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT ts, user, text FROM messages
+             WHERE channel = ?1 AND (ts = ?2 OR thread_ts = ?2)
+             ORDER BY CAST(ts AS REAL) ASC
+             LIMIT ?3",
+        )?;
+        let rows = stmt.query_map(params![channel, thread_ts, limit], |row| {
+            let ts: String = row.get(0)?;
+            Ok(ReplyInfo {
+                timestamp: parse_slack_ts(&ts).ok(),
+                ts,
+                user: row.get(1)?,
+                text: row.get(2)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Non-deleted users whose name, real name, display name or email contains `query`.
+    pub fn search_users(&self, query: &str) -> Result<Vec<UserInfo>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, name, real_name, display_name, email, title, is_admin, is_bot, deleted, tz
+             FROM users
+             WHERE deleted = 0
+               AND (instr(lower(name), ?1) > 0
+                    OR instr(lower(coalesce(real_name, '')), ?1) > 0
+                    OR instr(lower(coalesce(display_name, '')), ?1) > 0
+                    OR instr(lower(coalesce(email, '')), ?1) > 0)
+             ORDER BY name",
+        )?;
+        let rows = stmt.query_map(params![query.to_lowercase()], |row| {
+            Ok(UserInfo {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                real_name: row.get(2)?,
+                display_name: row.get(3)?,
+                email: row.get(4)?,
+                title: row.get(5)?,
+                is_admin: row.get(6)?,
+                is_bot: row.get(7)?,
+                deleted: row.get(8)?,
+                tz: row.get(9)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+}
+
+fn raw_json<T: serde::Serialize>(value: &T) -> Result<String> {
+    Ok(serde_json::to_string(value).map_err(anyhow::Error::from)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn mirror() -> Mirror {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(SCHEMA).unwrap();
+        Mirror { conn }
+    }
+
+    fn message(value: serde_json::Value) -> SlackHistoryMessage {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn reactions(mirror: &Mirror, ts: &str) -> Vec<(String, String)> {
+        let mut stmt = mirror
+            .connection()
+            .prepare("SELECT name, user FROM reactions WHERE ts = ?1 ORDER BY name, user")
+            .unwrap();
+        stmt.query_map(params![ts], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_history_and_replies() {
+        let m = mirror();
+        let conn = m.connection();
+        for value in [
+            json!({"ts": "100.000001", "user": "U1", "text": "plain"}),
+            json!({"ts": "200.000001", "user": "U1", "text": "parent",
+                   "thread_ts": "200.000001", "reply_count": 2, "latest_reply": "202.000001"}),
+            json!({"ts": "201.000001", "user": "U2", "text": "reply", "thread_ts": "200.000001"}),
+            json!({"ts": "202.000001", "user": "U2", "text": "broadcast",
+                   "thread_ts": "200.000001", "subtype": "thread_broadcast"}),
+        ] {
+            Mirror::upsert_message(conn, "C1", &message(value)).unwrap();
+        }
+
+        // Plain replies stay out of history; broadcasts show up, newest first
+        let history = m.history("C1", 10, None, None).unwrap();
+        let texts: Vec<&str> = history.iter().map(|h| h.text.as_str()).collect();
+        assert_eq!(texts, ["broadcast", "parent", "plain"]);
+        assert_eq!(history[1].reply_count, Some(2));
+
+        let bounded = m
+            .history("C1", 10, Some("100.000001"), Some("202.000001"))
+            .unwrap();
+        assert_eq!(bounded.len(), 1);
+        assert_eq!(bounded[0].text, "parent");
+
+        let replies = m.replies("C1", "200.000001", 10).unwrap();
+        let texts: Vec<&str> = replies.iter().map(|r| r.text.as_str()).collect();
+        assert_eq!(texts, ["parent", "reply", "broadcast"]);
+        assert_eq!(
+            m.latest_reply("C1", "200.000001").unwrap().as_deref(),
+            Some("202.000001")
+        );
+        assert!(m.history("C2", 10, None, None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_upsert_replaces_message_and_reactions() {
+        let m = mirror();
+        let conn = m.connection();
+        let first = json!({"ts": "1.000001", "user": "U1", "text": "draft",
+            "reactions": [{"name": "eyes", "count": 2, "users": ["U2", "U3"]}]});
+        Mirror::upsert_message(conn, "C1", &message(first)).unwrap();
+        assert_eq!(
+            reactions(&m, "1.000001"),
+            [("eyes".into(), "U2".into()), ("eyes".into(), "U3".into())]
+        );
+
+        let edited = json!({"ts": "1.000001", "user": "U1", "text": "final",
+            "reactions": [{"name": "tada", "count": 1, "users": ["U3"]}]});
+        Mirror::upsert_message(conn, "C1", &message(edited)).unwrap();
+        assert_eq!(reactions(&m, "1.000001"), [("tada".into(), "U3".into())]);
+
+        let history = m.history("C1", 10, None, None).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].text, "final");
+    }
+
+    #[test]
+    fn test_cursor() {
+        let m = mirror();
+        assert_eq!(m.cursor("C1").unwrap(), None);
+
+        Mirror::set_cursor(m.connection(), "C1", Some("5.000001")).unwrap();
+        assert_eq!(m.cursor("C1").unwrap().as_deref(), Some("5.000001"));
+
+        Mirror::set_cursor(m.connection(), "C1", Some("9.000001")).unwrap();
+        assert_eq!(m.cursor("C1").unwrap().as_deref(), Some("9.000001"));
+        assert_eq!(m.cursor("C2").unwrap(), None);
+
+        Mirror::set_cursor(m.connection(), "C1", None).unwrap();
+        assert_eq!(m.cursor("C1").unwrap(), None);
+    }
+
+    #[test]
+    fn test_search_users() {
+        let m = mirror();
+        for value in [
+            json!({"id": "U1", "name": "alice", "deleted": false,
+                   "profile": {"real_name": "Alice Smith", "email": "alice@example.com"}}),
+            json!({"id": "U2", "name": "bob", "deleted": false,
+                   "profile": {"real_name": "Bob Jones", "display_name": "bobby"}}),
+            json!({"id": "U3", "name": "alicia", "deleted": true}),
+        ] {
+            let user: SlackUser = serde_json::from_value(value).unwrap();
+            Mirror::upsert_user(m.connection(), &user).unwrap();
+        }
+
+        let ids = |query: &str| -> Vec<String> {
+            m.search_users(query)
+                .unwrap()
+                .into_iter()
+                .map(|u| u.id)
+                .collect()
+        };
+        assert_eq!(ids("ALI"), ["U1"]);
+        assert_eq!(ids("bobby"), ["U2"]);
+        assert_eq!(ids("example.com"), ["U1"]);
+        assert!(ids("carol").is_empty());
+    }
+}
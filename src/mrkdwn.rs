@@ -0,0 +1,267 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// Convert CommonMark to Slack mrkdwn: bold, italics, strikethrough, links, lists,
+/// code (inline and fenced), headings (as bold lines) and block quotes.
+pub fn markdown_to_mrkdwn(markdown: &str) -> String {
+    let mut out = String::new();
+    // Destination URL and start of the link text in `out`
+    let mut links: Vec<(String, usize)> = Vec::new();
+    // Next number for ordered lists, None for bullet lists
+    let mut lists: Vec<Option<u64>> = Vec::new();
+    // Start of each open block quote in `out`
+    let mut quotes: Vec<usize> = Vec::new();
+    let mut in_heading = false;
+
+    for event in Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH) {
+        match event {
+            Event::Start(tag) => match tag {
+                Tag::Heading { .. } => {
+                    in_heading = true;
+                    out.push('*');
+                }
+                Tag::BlockQuote(_) => quotes.push(out.len()),
+                Tag::CodeBlock(_) => {
+                    end_line(&mut out);
+                    out.push_str("```\n");
+                }
+                Tag::List(start) => {
+                    end_line(&mut out);
+                    lists.push(start);
+                }
+                Tag::Item => {
+                    end_line(&mut out);
+                    out.push_str(&"    ".repeat(lists.len().saturating_sub(1)));
+                    match lists.last_mut() {
+                        Some(Some(n)) => {
+                            out.push_str(&format!("{}. ", n));
+                            *n += 1;
+                        }
+                        _ => out.push_str("• "),
+                    }
+                }
+                Tag::Emphasis => out.push('_'),
+                Tag::Strong if !in_heading => out.push('*'),
+                Tag::Strikethrough => out.push('~'),
+                Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                    links.push((dest_url.to_string(), out.len()))
+                }
+                _ => {}
+            },
+            Event::End(tag) => match tag {
+                TagEnd::Paragraph if !lists.is_empty() => end_line(&mut out),
+                TagEnd::Paragraph => end_block(&mut out),
+                TagEnd::Heading(_) => {
+                    in_heading = false;
+                    out.push('*');
+                    end_block(&mut out);
+                }
+                TagEnd::BlockQuote(_) => {
+                    let start = quotes.pop().unwrap_or_default();
+                    let quoted = out.split_off(start);
+                    for line in quoted.trim_end().lines() {
+                        out.push_str(if line.is_empty() { ">" } else { "> " });
+                        out.push_str(line);
+                        out.push('\n');
+                    }
+                    end_block(&mut out);
+                }
+                TagEnd::CodeBlock => {
+                    end_line(&mut out);
+                    out.push_str("```");
+                    if lists.is_empty() {
+                        end_block(&mut out);
+                    } else {
+                        end_line(&mut out);
+                    }
+                }
+                TagEnd::List(_) => {
+                    lists.pop();
+                    if lists.is_empty() {
+                        end_block(&mut out);
+                    }
+                }
+                TagEnd::Item => end_line(&mut out),
+                TagEnd::Emphasis => out.push('_'),
+                TagEnd::Strong if !in_heading => out.push('*'),
+                TagEnd::Strikethrough => out.push('~'),
+                TagEnd::Link | TagEnd::Image => {
+                    let (url, start) = links.pop().unwrap_or_default();
+                    let text = out.split_off(start);
+                    if text.is_empty() || text == url {
+                        out.push_str(&format!("<{}>", url));
+                    } else {
+                        out.push_str(&format!("<{}|{}>", url, text));
+                    }
+                }
+                _ => {}
+            },
+            Event::Text(text) | Event::Html(text) | Event::InlineHtml(text) => {
+                out.push_str(&escape(&text))
+            }
+            Event::Code(code) => {
+                out.push('`');
+                out.push_str(&escape(&code));
+                out.push('`');
+            }
+            Event::SoftBreak | Event::HardBreak => out.push('\n'),
+            Event::Rule => {
+                out.push_str("──────────");
+                end_block(&mut out);
+            }
+            _ => {}
+        }
+    }
+
+    out.trim_end().to_string()
+}
+
+/// Slack treats `&`, `<` and `>` as control characters in message text.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn end_line(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn end_block(out: &mut String) {
+    end_line(out);
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+/// Room kept in each chunk for closing and reopening a code fence.
+const FENCE_RESERVE: usize = 8;
+
+/// Split text into chunks of at most `max` characters on line boundaries. Overlong lines
+/// are cut across chunks, and a code fence that spans chunks is closed and reopened.
+pub fn split_text(text: &str, max: usize) -> Vec<String> {
+    let width = max.saturating_sub(FENCE_RESERVE).max(1);
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut len = 0;
+    let mut in_fence = false;
+    // Set after a cut, so the rest of the line starts the next chunk instead of a new line
+    let mut cut = false;
+
+    for line in text.lines() {
+        let pieces = cut_line(line, width);
+        let last = pieces.len() - 1;
+        for (i, piece) in pieces.into_iter().enumerate() {
+            let piece_len = piece.chars().count();
+            let reserve = if in_fence { 4 } else { 0 };
+            if cut || (!current.is_empty() && len + 1 + piece_len + reserve > max) {
+                if in_fence {
+                    current.push_str("\n```");
+                }
+                chunks.push(std::mem::take(&mut current));
+                len = 0;
+                if in_fence {
+                    current.push_str("```");
+                    len = 3;
+                }
+            }
+            if !current.is_empty() {
+                current.push('\n');
+                len += 1;
+            }
+            current.push_str(piece);
+            len += piece_len;
+            cut = i < last;
+        }
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+    }
+    if !current.trim().is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Cut a line into pieces of at most `width` characters.
+fn cut_line(line: &str, width: usize) -> Vec<&str> {
+    if line.is_empty() {
+        return vec![line];
+    }
+    let mut pieces = Vec::new();
+    let mut rest = line;
+    while !rest.is_empty() {
+        let end = rest
+            .char_indices()
+            .nth(width)
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+        pieces.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_formatting() {
+        assert_eq!(
+            markdown_to_mrkdwn("**bold**, *italic*, _also_, ~~gone~~ and `a < b`"),
+            "*bold*, _italic_, _also_, ~gone~ and `a &lt; b`"
+        );
+    }
+
+    #[test]
+    fn test_links() {
+        assert_eq!(
+            markdown_to_mrkdwn("[docs](https://example.com) <https://example.com>"),
+            "<https://example.com|docs> <https://example.com>"
+        );
+    }
+
+    #[test]
+    fn test_headings_and_paragraphs() {
+        assert_eq!(
+            markdown_to_mrkdwn("# Release **notes**\n\nFirst.\nSecond."),
+            "*Release notes*\n\nFirst.\nSecond."
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(
+            markdown_to_mrkdwn("- one\n- two\n  - nested\n\n3. three\n4. four"),
+            "• one\n• two\n    • nested\n\n3. three\n4. four"
+        );
+    }
+
+    #[test]
+    fn test_code_block_and_quote() {
+        assert_eq!(
+            markdown_to_mrkdwn("```rust\nlet x = 1;\n```\n\n> quoted\n> text"),
+            "```\nlet x = 1;\n```\n\n> quoted\n> text"
+        );
+    }
+
+    #[test]
+    fn test_split_lines() {
+        let chunks = split_text("aaaaaaaa\nbbbbbbbb\ncccccccc", 18);
+        assert_eq!(chunks, vec!["aaaaaaaa\nbbbbbbbb", "cccccccc"]);
+    }
+
+    #[test]
+    fn test_split_long_line() {
+        let chunks = split_text(&"x".repeat(25), 18);
+        assert_eq!(chunks, vec!["x".repeat(10), "x".repeat(10), "x".repeat(5)]);
+    }
+
+    #[test]
+    fn test_split_reopens_fence() {
+        let chunks = split_text("```\nline 1\nline 2\n```", 18);
+        assert_eq!(chunks, vec!["```\nline 1\n```", "```\nline 2\n```"]);
+    }
+}
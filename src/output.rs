@@ -126,6 +126,23 @@ impl Output {
     pub fn is_json(&self) -> bool {
         self.format == OutputFormat::Json
     }
+
+    /// Ask a yes/no question on stderr and read the answer from stdin (defaults to no).
+    /// Returns an error when stdin isn't a terminal, so scripts never hang on a prompt.
+    pub fn confirm(&self, prompt: &str) -> Result<bool, SlackCliError> {
+        use std::io::{BufRead, IsTerminal, Write};
+
+        if !std::io::stdin().is_terminal() {
+            return Err(SlackCliError::Config(
+                "Confirmation needed but stdin is not a terminal".to_string(),
+            ));
+        }
+        eprint!("{} [y/N] ", prompt);
+        std::io::stderr().flush()?;
+        let mut answer = String::new();
+        std::io::stdin().lock().read_line(&mut answer)?;
+        Ok(matches!(answer.trim(), "y" | "Y" | "yes" | "YES" | "Yes"))
+    }
 }
 
 pub trait HumanReadable {
@@ -0,0 +1,118 @@
+/// A message addressed by a Slack permalink.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageRef {
+    pub channel: String,
+    pub ts: String,
+    /// Parent message when the link points at a thread reply
+    pub thread_ts: Option<String>,
+}
+
+impl MessageRef {
+    /// The thread this message belongs to: its parent, or the message itself.
+    pub fn thread(&self) -> &str {
+        self.thread_ts.as_deref().unwrap_or(&self.ts)
+    }
+}
+
+/// Parse a message permalink such as
+/// `https://acme.slack.com/archives/C1RCG46LS/p1769415774159039?thread_ts=1769415700.000100&cid=C1RCG46LS`.
+pub fn parse_permalink(url: &str) -> Option<MessageRef> {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let (host, rest) = rest.split_once('/')?;
+    if !(host == "slack.com" || host.ends_with(".slack.com")) {
+        return None;
+    }
+
+    let (path, query) = match rest.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (rest, None),
+    };
+    let mut segments = path.trim_end_matches('/').split('/');
+    if segments.next()? != "archives" {
+        return None;
+    }
+    let channel = segments.next()?.to_string();
+    let ts = decode_p_ts(segments.next()?)?;
+    if channel.is_empty() || segments.next().is_some() {
+        return None;
+    }
+
+    let thread_ts = query
+        .into_iter()
+        .flat_map(|q| q.split('&'))
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "thread_ts")
+        .and_then(|(_, value)| urlencoding::decode(value).ok())
+        .map(|value| value.into_owned())
+        .filter(|thread_ts| *thread_ts != ts);
+
+    Some(MessageRef {
+        channel,
+        ts,
+        thread_ts,
+    })
+}
+
+/// `p1769415774159039` → `1769415774.159039`
+fn decode_p_ts(segment: &str) -> Option<String> {
+    let digits = segment.strip_prefix('p')?;
+    if digits.len() <= 6 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (secs, micros) = digits.split_at(digits.len() - 6);
+    Some(format!("{}.{}", secs, micros))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_link() {
+        let link =
+            parse_permalink("https://acme.slack.com/archives/C1RCG46LS/p1769415774159039").unwrap();
+        assert_eq!(link.channel, "C1RCG46LS");
+        assert_eq!(link.ts, "1769415774.159039");
+        assert_eq!(link.thread_ts, None);
+        assert_eq!(link.thread(), "1769415774.159039");
+    }
+
+    #[test]
+    fn test_reply_link() {
+        let link = parse_permalink(
+            "https://acme.slack.com/archives/C1RCG46LS/p1769415774159039?thread_ts=1769415700.000100&cid=C1RCG46LS",
+        )
+        .unwrap();
+        assert_eq!(link.ts, "1769415774.159039");
+        assert_eq!(link.thread_ts.as_deref(), Some("1769415700.000100"));
+        assert_eq!(link.thread(), "1769415700.000100");
+    }
+
+    #[test]
+    fn test_encoded_thread_ts() {
+        let link = parse_permalink(
+            "https://acme.slack.com/archives/C1/p1769415774159039?thread_ts=1769415700%2E000100",
+        )
+        .unwrap();
+        assert_eq!(link.thread_ts.as_deref(), Some("1769415700.000100"));
+    }
+
+    #[test]
+    fn test_not_a_permalink() {
+        assert_eq!(parse_permalink("C1RCG46LS"), None);
+        assert_eq!(
+            parse_permalink("https://example.com/archives/C1/p1769415774159039"),
+            None
+        );
+        assert_eq!(
+            parse_permalink("https://acme.slack.com/archives/C1RCG46LS"),
+            None
+        );
+        assert_eq!(
+            parse_permalink("https://acme.slack.com/archives/C1/pabc"),
+            None
+        );
+    }
+}
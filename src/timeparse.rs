@@ -1,29 +1,56 @@
-use chrono::{Local, NaiveDate, TimeDelta, Utc};
+use chrono::{
+    DateTime, Datelike, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Utc, Weekday,
+};
 
 /// Parse a time expression into a Slack timestamp string (unix epoch with `.000000` suffix).
 ///
 /// Accepted formats:
-/// - `today` — start of today (local time)
-/// - Relative: `30m`, `1h`, `2d` (minutes, hours, days ago from now)
+/// - `today` / `tomorrow` — start of the day (local time), optionally with a clock time
+///   (`today 17:00`, `tomorrow at 9am`)
+/// - Weekdays: `friday`, `next monday 9:30` (the next one after today), `last friday`
+/// - Clock time: `17:00`, `5:30pm` — today at that time (local time)
+/// - Relative past: `30m`, `1h`, `2d`, `1w`, `3 hours ago` (from now)
+/// - Relative future: `+30m`, `in 2h`, `in 3 days`
 /// - ISO 8601: `2024-01-15T10:30:00Z` or `2024-01-15`
 pub fn parse_time_expr(s: &str) -> Result<String, String> {
     let s = s.trim();
+    let lower = s.to_ascii_lowercase();
 
-    if s.eq_ignore_ascii_case("today") {
-        let today = Local::now()
-            .date_naive()
-            .and_hms_opt(0, 0, 0)
-            .ok_or("failed to compute start of today")?;
-        let ts = today
-            .and_local_timezone(Local)
-            .single()
-            .ok_or("ambiguous local time")?
-            .timestamp();
-        return Ok(format!("{}.000000", ts));
-    }
-
-    // Relative: 30m, 1h, 2d
-    if let Some(val) = parse_relative(s) {
+    // "tomorrow at 9am" reads the same as "tomorrow 9am"
+    let words: Vec<&str> = lower.split_whitespace().filter(|w| *w != "at").collect();
+    let (day_offset, clock_words) = match words.as_slice() {
+        ["today", rest @ ..] => (Some(0), rest),
+        ["tomorrow", rest @ ..] => (Some(1), rest),
+        ["next", day, rest @ ..] => (weekday_offset(day, false), rest),
+        ["last", day, rest @ ..] => (weekday_offset(day, true), rest),
+        [day, rest @ ..] => (weekday_offset(day, false), rest),
+        [] => return Err(invalid(s)),
+    };
+    if let Some(offset) = day_offset {
+        let time = match clock_words {
+            [] => NaiveTime::MIN,
+            words => parse_clock(&words.concat()).ok_or_else(|| invalid(s))?,
+        };
+        let date = Local::now().date_naive() + TimeDelta::days(offset);
+        return local_ts(date.and_time(time));
+    }
+
+    if let Some(time) = parse_clock(&words.concat()) {
+        return local_ts(Local::now().date_naive().and_time(time));
+    }
+
+    // Relative future: +30m, in 2h, in 3 days
+    if let Some(rest) = lower
+        .strip_prefix('+')
+        .or_else(|| lower.strip_prefix("in "))
+    {
+        let val = parse_relative(rest).ok_or_else(|| invalid(s))?;
+        return Ok(format!("{}.000000", (Utc::now() + val).timestamp()));
+    }
+
+    // Relative: 30m, 1h, 2d, 3 hours ago
+    let past = lower.strip_suffix(" ago").unwrap_or(&lower);
+    if let Some(val) = parse_relative(past) {
         let now = Utc::now();
         let then = now - val;
         return Ok(format!("{}.000000", then.timestamp()));
@@ -36,34 +63,113 @@ pub fn parse_time_expr(s: &str) -> Result<String, String> {
 
     // ISO date only (YYYY-MM-DD)
     if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
-        let dt = date
-            .and_hms_opt(0, 0, 0)
-            .ok_or("failed to build datetime from date")?;
-        let ts = dt
-            .and_local_timezone(Local)
-            .single()
-            .ok_or("ambiguous local time")?
-            .timestamp();
-        return Ok(format!("{}.000000", ts));
-    }
-
-    Err(format!(
-        "Invalid time expression: '{}'. Use ISO timestamp, relative (1h/30m/2d), or 'today'.",
+        return local_ts(date.and_time(NaiveTime::MIN));
+    }
+
+    Err(invalid(s))
+}
+
+fn invalid(s: &str) -> String {
+    format!(
+        "Invalid time expression: '{}'. Use ISO timestamp, relative (1h/30m/2d ago, +1h/in 2 days \
+         ahead), 'today'/'tomorrow'/a weekday with an optional time, or a time like 17:30 or 5pm.",
         s
-    ))
+    )
 }
 
-fn parse_relative(s: &str) -> Option<TimeDelta> {
+/// Days from today to the next (or with `last`, the previous) given weekday, 1 to 7.
+fn weekday_offset(name: &str, last: bool) -> Option<i64> {
+    let target: Weekday = name.parse().ok()?;
+    let today = Local::now().weekday();
+    let ahead =
+        (target.num_days_from_monday() as i64 - today.num_days_from_monday() as i64).rem_euclid(7);
+    Some(match (last, ahead) {
+        (false, 0) => 7,
+        (false, n) => n,
+        (true, 0) => -7,
+        (true, n) => n - 7,
+    })
+}
+
+/// `HH:MM`, `HH:MM:SS`, or 12-hour `9am` / `5:30pm`.
+fn parse_clock(s: &str) -> Option<NaiveTime> {
+    if let Some((clock, pm)) = s
+        .strip_suffix("am")
+        .map(|c| (c, false))
+        .or_else(|| s.strip_suffix("pm").map(|c| (c, true)))
+    {
+        let (hour, minute) = match clock.split_once(':') {
+            Some((h, m)) => (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?),
+            None => (clock.parse::<u32>().ok()?, 0),
+        };
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+        let hour = hour % 12 + if pm { 12 } else { 0 };
+        return NaiveTime::from_hms_opt(hour, minute, 0);
+    }
+
+    NaiveTime::parse_from_str(s, "%H:%M")
+        .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M:%S"))
+        .ok()
+}
+
+fn local_ts(dt: NaiveDateTime) -> Result<String, String> {
+    let ts = dt
+        .and_local_timezone(Local)
+        .single()
+        .ok_or("ambiguous local time")?
+        .timestamp();
+    Ok(format!("{}.000000", ts))
+}
+
+/// Parse a Slack message timestamp (`1769415774.159039`) into the time it stands for.
+pub fn parse_slack_ts(ts: &str) -> Result<DateTime<Utc>, String> {
+    let invalid = || format!("Invalid Slack timestamp: '{}'", ts);
+    let (secs, micros) = ts.split_once('.').unwrap_or((ts, ""));
+    if secs.is_empty()
+        || micros.len() > 6
+        || !secs
+            .chars()
+            .chain(micros.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let secs: i64 = secs.parse().map_err(|_| invalid())?;
+    let micros: u32 = format!("{:0<6}", micros).parse().map_err(|_| invalid())?;
+    DateTime::from_timestamp(secs, micros * 1000).ok_or_else(invalid)
+}
+
+/// Parse a polling interval like `10s`, `5m` or `1h` (a bare number is seconds).
+pub fn parse_interval(s: &str) -> Result<std::time::Duration, String> {
     let s = s.trim();
-    if s.len() < 2 {
-        return None;
+    let invalid = || format!("Invalid interval: '{}'. Use e.g. 10s, 5m or 1h.", s);
+
+    let (num_str, multiplier) = match s.char_indices().last() {
+        Some((i, 's')) => (&s[..i], 1),
+        Some((i, 'm')) => (&s[..i], 60),
+        Some((i, 'h')) => (&s[..i], 3600),
+        _ => (s, 1),
+    };
+    let num: u64 = num_str.parse().map_err(|_| invalid())?;
+    if num == 0 {
+        return Err(invalid());
     }
-    let (num_str, unit) = s.split_at(s.len() - 1);
+    Ok(std::time::Duration::from_secs(num * multiplier))
+}
+
+/// `30m`, `2h`, `3d`, `1w`, or spelled out: `30 minutes`, `2 hours`, `1 week`.
+fn parse_relative(s: &str) -> Option<TimeDelta> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit())?;
+    let (num_str, unit) = s.split_at(split);
     let num: i64 = num_str.parse().ok()?;
-    match unit {
-        "m" => TimeDelta::try_minutes(num),
-        "h" => TimeDelta::try_hours(num),
-        "d" => TimeDelta::try_days(num),
+    match unit.trim_start() {
+        "m" | "min" | "mins" | "minute" | "minutes" => TimeDelta::try_minutes(num),
+        "h" | "hr" | "hrs" | "hour" | "hours" => TimeDelta::try_hours(num),
+        "d" | "day" | "days" => TimeDelta::try_days(num),
+        "w" | "week" | "weeks" => TimeDelta::try_weeks(num),
         _ => None,
     }
 }
@@ -120,4 +226,114 @@ mod tests {
     fn test_invalid() {
         assert!(parse_time_expr("foobar").is_err());
     }
+
+    #[test]
+    fn test_relative_future() {
+        let now = Utc::now().timestamp() as f64;
+        let ts: f64 = parse_time_expr("+30m").unwrap().parse().unwrap();
+        assert!((ts - now - 1800.0).abs() < 5.0);
+        let ts: f64 = parse_time_expr("in 2h").unwrap().parse().unwrap();
+        assert!((ts - now - 7200.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_tomorrow() {
+        let today: i64 = parse_time_expr("today").unwrap().parse::<f64>().unwrap() as i64;
+        let tomorrow: i64 = parse_time_expr("tomorrow").unwrap().parse::<f64>().unwrap() as i64;
+        // 23-25h apart across DST changes
+        assert!((82800..=90000).contains(&(tomorrow - today)));
+    }
+
+    #[test]
+    fn test_clock_time() {
+        let today: i64 = parse_time_expr("today").unwrap().parse::<f64>().unwrap() as i64;
+        let at: i64 = parse_time_expr("17:30").unwrap().parse::<f64>().unwrap() as i64;
+        assert_eq!(
+            at,
+            parse_time_expr("today 17:30")
+                .unwrap()
+                .parse::<f64>()
+                .unwrap() as i64
+        );
+        assert!(at > today);
+        assert!(parse_time_expr("tomorrow 9:00").is_ok());
+        assert!(parse_time_expr("tomorrow 25:00").is_err());
+    }
+
+    #[test]
+    fn test_interval() {
+        assert_eq!(parse_interval("10s").unwrap().as_secs(), 10);
+        assert_eq!(parse_interval("5m").unwrap().as_secs(), 300);
+        assert_eq!(parse_interval("1h").unwrap().as_secs(), 3600);
+        assert_eq!(parse_interval("30").unwrap().as_secs(), 30);
+    }
+
+    #[test]
+    fn test_interval_invalid() {
+        assert!(parse_interval("0s").is_err());
+        assert!(parse_interval("s").is_err());
+        assert!(parse_interval("10x").is_err());
+    }
+
+    #[test]
+    fn test_natural_relative() {
+        let now = Utc::now().timestamp() as f64;
+        let ts: f64 = parse_time_expr("in 3 days").unwrap().parse().unwrap();
+        assert!((ts - now - 3.0 * 86400.0).abs() < 5.0);
+        let ts: f64 = parse_time_expr("2 hours ago").unwrap().parse().unwrap();
+        assert!((now - ts - 7200.0).abs() < 5.0);
+        let ts: f64 = parse_time_expr("1w").unwrap().parse().unwrap();
+        assert!((now - ts - 7.0 * 86400.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_twelve_hour_clock() {
+        let at = |s: &str| parse_time_expr(s).unwrap().parse::<f64>().unwrap() as i64;
+        assert_eq!(at("5:30pm"), at("17:30"));
+        assert_eq!(at("tomorrow at 9am"), at("tomorrow 9:00"));
+        assert_eq!(at("today 12am"), at("today"));
+        assert!(parse_time_expr("13pm").is_err());
+    }
+
+    #[test]
+    fn test_weekdays() {
+        let at = |s: &str| parse_time_expr(s).unwrap().parse::<f64>().unwrap() as i64;
+        let today = at("today");
+        let next = at("friday");
+        let last = at("last friday");
+        assert_eq!(next, at("next friday"));
+        assert!(next > today && next - today <= 7 * 86400 + 3600);
+        assert!(last < today && today - last <= 7 * 86400 + 3600);
+        assert!(at("monday 9:30") > today);
+        assert!(parse_time_expr("someday").is_err());
+    }
+
+    #[test]
+    fn test_slack_ts() {
+        let t = parse_slack_ts("1769415774.159039").unwrap();
+        assert_eq!(t.timestamp(), 1769415774);
+        assert_eq!(t.timestamp_subsec_micros(), 159039);
+        assert_eq!(
+            parse_slack_ts("1769415774").unwrap().timestamp(),
+            1769415774
+        );
+        assert_eq!(
+            parse_slack_ts("1.5").unwrap().timestamp_subsec_micros(),
+            500000
+        );
+        // Orders by value, not by string
+        assert!(parse_slack_ts("99.000001").unwrap() < parse_slack_ts("100.0").unwrap());
+        for bad in ["", ".5", "abc", "-1.0", "1.2.3", "1.1234567", "12x.0"] {
+            assert!(parse_slack_ts(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_slack_ts_round_trips_time_expr() {
+        let ts = parse_time_expr("2024-01-15T10:30:00Z").unwrap();
+        assert_eq!(
+            parse_slack_ts(&ts).unwrap().to_rfc3339(),
+            "2024-01-15T10:30:00+00:00"
+        );
+    }
 }